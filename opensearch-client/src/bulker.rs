@@ -463,7 +463,7 @@ mod tests {
     client.indices().refresh_post().send().await.unwrap();
 
     let count = client.count().index("test").send().await.unwrap().into_inner();
-    assert_eq!(count.count, test_size);
+    assert_eq!(count.count, test_size as u64);
     Ok(())
   }
 }
@@ -11,6 +11,8 @@ use reqwest::RequestBuilder;
 use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 
+use crate::types::{ErrorCause, ErrorResponse};
+
 #[cfg(not(target_arch = "wasm32"))]
 type InnerByteStream = std::pin::Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send + Sync>>;
 
@@ -297,6 +299,136 @@ pub enum Error {
   UnexpectedResponse(ReqwestResponse),
 }
 
+/// Structured view of an [`Error`], telling failures of the connection apart
+/// from errors returned by OpenSearch.
+///
+/// ```
+/// # use opensearch_client::{Error, OpenSearchError};
+/// fn is_missing_index(error: Error) -> bool {
+///   match OpenSearchError::from(error) {
+///     OpenSearchError::Client {
+///       cause: Some(cause), ..
+///     } => cause.kind == "index_not_found_exception",
+///     _ => false,
+///   }
+/// }
+/// ```
+#[derive(Error, Debug)]
+pub enum OpenSearchError {
+  /// The request could not be sent or its response could not be read.
+  #[error(transparent)]
+  Transport(reqwest_middleware::Error),
+
+  /// The request timed out.
+  #[error("Request timed out: {0}")]
+  Timeout(reqwest_middleware::Error),
+
+  /// OpenSearch rejected the request with a 4xx status.
+  ///
+  /// `cause` is parsed from `body` when it is an OpenSearch error envelope.
+  #[error("Client error {status}{}", describe_cause(.cause))]
+  Client {
+    status: reqwest::StatusCode,
+    cause: Option<ErrorCause>,
+    body: String,
+  },
+
+  /// OpenSearch, or a proxy in front of it, failed to handle the request with
+  /// a 5xx status.
+  ///
+  /// `cause` is parsed from `body` when it is an OpenSearch error envelope.
+  #[error("Server error {status}{}", describe_cause(.cause))]
+  Server {
+    status: reqwest::StatusCode,
+    cause: Option<ErrorCause>,
+    body: String,
+  },
+
+  /// Any other error, such as an invalid request or a response body that
+  /// could not be decoded.
+  #[error(transparent)]
+  Other(Error),
+}
+
+fn describe_cause(cause: &Option<ErrorCause>) -> String {
+  cause.as_ref().map(|cause| format!(": {cause}")).unwrap_or_default()
+}
+
+impl OpenSearchError {
+  /// Whether sending the same request again may succeed, that is on
+  /// connection failures, timeouts, `429 Too Many Requests` and
+  /// `502`/`503`/`504` responses.
+  pub fn is_retryable(&self) -> bool {
+    match self {
+      Self::Transport(_) | Self::Timeout(_) => true,
+      Self::Client { status, .. } => *status == reqwest::StatusCode::TOO_MANY_REQUESTS,
+      Self::Server { status, .. } => matches!(status.as_u16(), 502..=504),
+      Self::Other(_) => false,
+    }
+  }
+
+  /// Status of the response, if OpenSearch returned one.
+  pub fn status(&self) -> Option<reqwest::StatusCode> {
+    match self {
+      Self::Client { status, .. } | Self::Server { status, .. } => Some(*status),
+      _ => None,
+    }
+  }
+
+  /// Cause parsed from the error body returned by OpenSearch.
+  pub fn cause(&self) -> Option<&ErrorCause> {
+    match self {
+      Self::Client { cause, .. } | Self::Server { cause, .. } => cause.as_ref(),
+      _ => None,
+    }
+  }
+
+  /// Raw body of the response, if OpenSearch returned one.
+  pub fn body(&self) -> Option<&str> {
+    match self {
+      Self::Client { body, .. } | Self::Server { body, .. } => Some(body),
+      _ => None,
+    }
+  }
+}
+
+impl From<reqwest_middleware::Error> for OpenSearchError {
+  fn from(error: reqwest_middleware::Error) -> Self {
+    if error.is_timeout() {
+      Self::Timeout(error)
+    } else {
+      Self::Transport(error)
+    }
+  }
+}
+
+impl From<Error> for OpenSearchError {
+  fn from(error: Error) -> Self {
+    match error {
+      Error::CommunicationError(error) => error.into(),
+      Error::UnexpectedResponse(response) if response.status.is_client_error() || response.status.is_server_error() => {
+        let cause = serde_json::from_str::<ErrorResponse>(&response.value)
+          .ok()
+          .map(|body| body.error);
+        if response.status.is_client_error() {
+          Self::Client {
+            status: response.status,
+            cause,
+            body: response.value,
+          }
+        } else {
+          Self::Server {
+            status: response.status,
+            cause,
+            body: response.value,
+          }
+        }
+      }
+      error => Self::Other(error),
+    }
+  }
+}
+
 trait ErrorFormat {
   fn fmt_info(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
 }
@@ -370,3 +502,77 @@ impl<E> RequestBuilderExt<E> for RequestBuilder {
     )
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn response(status: u16, value: &str) -> Error {
+    Error::UnexpectedResponse(ReqwestResponse {
+      status: reqwest::StatusCode::from_u16(status).unwrap(),
+      headers: reqwest::header::HeaderMap::new(),
+      value: value.to_string(),
+    })
+  }
+
+  #[test]
+  fn test_client_error_with_cause() {
+    let error = OpenSearchError::from(response(
+      404,
+      r#"{"error":{"root_cause":[{"type":"index_not_found_exception","reason":"no such index [movies]"}],"type":"index_not_found_exception","reason":"no such index [movies]"},"status":404}"#,
+    ));
+
+    assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    assert_eq!(error.cause().unwrap().kind, "index_not_found_exception");
+    assert!(!error.is_retryable());
+    assert_eq!(
+      error.to_string(),
+      "Client error 404 Not Found: index_not_found_exception: no such index [movies]"
+    );
+  }
+
+  #[test]
+  fn test_server_error_without_body() {
+    let error = OpenSearchError::from(response(503, ""));
+
+    assert!(matches!(error, OpenSearchError::Server { cause: None, .. }));
+    assert!(error.is_retryable());
+    assert!(OpenSearchError::from(response(429, "")).is_retryable());
+    assert!(!OpenSearchError::from(response(500, "")).is_retryable());
+  }
+
+  #[test]
+  fn test_server_error_keeps_non_envelope_body() {
+    let html = "<html><body><h1>502 Bad Gateway</h1></body></html>";
+    let error = OpenSearchError::from(response(502, html));
+
+    assert!(error.cause().is_none());
+    assert_eq!(error.body(), Some(html));
+    assert_eq!(error.to_string(), "Server error 502 Bad Gateway");
+  }
+
+  #[tokio::test]
+  async fn test_invalid_response_payload_is_not_retryable() {
+    let payload_error = reqwest::Response::from(http::Response::new("not json"))
+      .json::<serde_json::Value>()
+      .await
+      .unwrap_err();
+    let error = OpenSearchError::from(Error::InvalidResponsePayload(payload_error));
+
+    assert!(matches!(
+      error,
+      OpenSearchError::Other(Error::InvalidResponsePayload(_))
+    ));
+    assert!(!error.is_retryable());
+    assert_eq!(error.status(), None);
+  }
+
+  #[test]
+  fn test_other_error() {
+    let error = OpenSearchError::from(Error::InvalidRequest("missing index".to_string()));
+
+    assert!(matches!(error, OpenSearchError::Other(Error::InvalidRequest(_))));
+    assert_eq!(error.status(), None);
+    assert!(!error.is_retryable());
+  }
+}
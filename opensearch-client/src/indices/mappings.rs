@@ -0,0 +1,470 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Serialize, Serializer};
+
+use super::types::IndexTemplateMapping;
+
+/// Mappings of an index, serialized to the `mappings` object of an index
+/// creation request.
+///
+/// ```
+/// use opensearch_client::indices::mappings::{FieldMapping, Mappings};
+///
+/// let mappings = Mappings::new()
+///   .property("title", FieldMapping::text().analyzer("english"))
+///   .property("genre", FieldMapping::keyword().ignore_above(256))
+///   .property("year", FieldMapping::long());
+///
+/// assert_eq!(
+///   serde_json::to_string(&mappings).unwrap(),
+///   "{\"properties\":{\"genre\":{\"type\":\"keyword\",\"ignore_above\":256},\"title\":{\"type\":\"text\",\"analyzer\":\"english\"},\"year\":{\"type\":\"long\"}}}"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct Mappings {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  dynamic: Option<DynamicMapping>,
+  properties: BTreeMap<String, FieldMapping>,
+}
+
+impl Mappings {
+  /// Creates mappings without any field
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Maps a field
+  pub fn property<T: ToString, M: Into<FieldMapping>>(mut self, name: T, mapping: M) -> Self {
+    self.properties.insert(name.to_string(), mapping.into());
+    self
+  }
+
+  /// How fields missing from the mappings are handled when indexing
+  /// documents, `true` and `false` adding or ignoring them
+  pub fn dynamic<D: Into<DynamicMapping>>(mut self, dynamic: D) -> Self {
+    self.dynamic = Some(dynamic.into());
+    self
+  }
+}
+
+impl From<Mappings> for IndexTemplateMapping {
+  fn from(value: Mappings) -> Self {
+    let mappings = match serde_json::to_value(value) {
+      Ok(serde_json::Value::Object(mappings)) => mappings.into_iter().collect(),
+      _ => HashMap::new(),
+    };
+    Self {
+      mappings,
+      ..Default::default()
+    }
+  }
+}
+
+/// How fields missing from the mappings are handled when indexing documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicMapping {
+  /// Missing fields are added to the mappings
+  True,
+  /// Missing fields are kept in `_source` but not indexed
+  False,
+  /// Documents with missing fields are rejected
+  Strict,
+}
+
+impl Serialize for DynamicMapping {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Self::True => serializer.serialize_bool(true),
+      Self::False => serializer.serialize_bool(false),
+      Self::Strict => serializer.serialize_str("strict"),
+    }
+  }
+}
+
+impl From<bool> for DynamicMapping {
+  fn from(value: bool) -> Self {
+    if value {
+      Self::True
+    } else {
+      Self::False
+    }
+  }
+}
+
+/// Mapping of a field, named after its `type`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FieldMapping {
+  Text(TextMapping),
+  Keyword(KeywordMapping),
+  Long,
+  Double,
+  Date(DateMapping),
+  Boolean,
+  Object(ObjectMapping),
+  Nested(ObjectMapping),
+  GeoPoint,
+  Ip,
+  KnnVector(KnnVectorMapping),
+}
+
+impl FieldMapping {
+  /// Full-text field, analyzed into terms
+  pub fn text() -> TextMapping {
+    TextMapping::default()
+  }
+
+  /// Exact-value field, such as an identifier or a tag
+  pub fn keyword() -> KeywordMapping {
+    KeywordMapping::default()
+  }
+
+  /// Signed 64-bit integer field
+  pub fn long() -> Self {
+    Self::Long
+  }
+
+  /// Double-precision floating point field
+  pub fn double() -> Self {
+    Self::Double
+  }
+
+  /// Date field
+  pub fn date() -> DateMapping {
+    DateMapping::default()
+  }
+
+  /// Boolean field
+  pub fn boolean() -> Self {
+    Self::Boolean
+  }
+
+  /// Object field, whose sub-fields are flattened into the document
+  pub fn object() -> ObjectMapping {
+    ObjectMapping::default()
+  }
+
+  /// Array of objects field, each queried independently of the others
+  pub fn nested() -> NestedMapping {
+    NestedMapping::default()
+  }
+
+  /// Latitude and longitude field
+  pub fn geo_point() -> Self {
+    Self::GeoPoint
+  }
+
+  /// IPv4 or IPv6 address field
+  pub fn ip() -> Self {
+    Self::Ip
+  }
+
+  /// Vector field for k-NN searches, with `dimension` components
+  pub fn knn_vector(dimension: u32) -> KnnVectorMapping {
+    KnnVectorMapping {
+      dimension,
+      method: None,
+    }
+  }
+}
+
+/// Options of a [`FieldMapping::Text`] field.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct TextMapping {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  analyzer: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  search_analyzer: Option<String>,
+  #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+  fields: BTreeMap<String, FieldMapping>,
+}
+
+impl TextMapping {
+  /// Analyzer used when indexing, and searching unless `search_analyzer` is
+  /// set
+  pub fn analyzer<T: ToString>(mut self, analyzer: T) -> Self {
+    self.analyzer = Some(analyzer.to_string());
+    self
+  }
+
+  /// Analyzer used when searching
+  pub fn search_analyzer<T: ToString>(mut self, search_analyzer: T) -> Self {
+    self.search_analyzer = Some(search_analyzer.to_string());
+    self
+  }
+
+  /// Indexes the field a second way under `name`, such as a `keyword`
+  /// sub-field for sorting and aggregations
+  pub fn field<T: ToString, M: Into<FieldMapping>>(mut self, name: T, mapping: M) -> Self {
+    self.fields.insert(name.to_string(), mapping.into());
+    self
+  }
+}
+
+/// Options of a [`FieldMapping::Keyword`] field.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct KeywordMapping {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  ignore_above: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  normalizer: Option<String>,
+}
+
+impl KeywordMapping {
+  /// Values longer than this number of characters aren't indexed
+  pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+    self.ignore_above = Some(ignore_above);
+    self
+  }
+
+  /// Normalizer applied to values before they are indexed
+  pub fn normalizer<T: ToString>(mut self, normalizer: T) -> Self {
+    self.normalizer = Some(normalizer.to_string());
+    self
+  }
+}
+
+/// Options of a [`FieldMapping::Date`] field.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct DateMapping {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  format: Option<String>,
+}
+
+impl DateMapping {
+  /// Formats dates are parsed with, such as `yyyy-MM-dd||epoch_millis`
+  pub fn format<T: ToString>(mut self, format: T) -> Self {
+    self.format = Some(format.to_string());
+    self
+  }
+}
+
+/// Options of a [`FieldMapping::Object`] or [`FieldMapping::Nested`] field.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct ObjectMapping {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  dynamic: Option<DynamicMapping>,
+  #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+  properties: BTreeMap<String, FieldMapping>,
+}
+
+impl ObjectMapping {
+  /// Maps a sub-field
+  pub fn property<T: ToString, M: Into<FieldMapping>>(mut self, name: T, mapping: M) -> Self {
+    self.properties.insert(name.to_string(), mapping.into());
+    self
+  }
+
+  /// How sub-fields missing from the mappings are handled when indexing
+  /// documents, `true` and `false` adding or ignoring them
+  pub fn dynamic<D: Into<DynamicMapping>>(mut self, dynamic: D) -> Self {
+    self.dynamic = Some(dynamic.into());
+    self
+  }
+}
+
+/// Options of a [`FieldMapping::Nested`] field.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NestedMapping(ObjectMapping);
+
+impl NestedMapping {
+  /// Maps a sub-field
+  pub fn property<T: ToString, M: Into<FieldMapping>>(self, name: T, mapping: M) -> Self {
+    Self(self.0.property(name, mapping))
+  }
+
+  /// How sub-fields missing from the mappings are handled when indexing
+  /// documents, `true` and `false` adding or ignoring them
+  pub fn dynamic<D: Into<DynamicMapping>>(self, dynamic: D) -> Self {
+    Self(self.0.dynamic(dynamic))
+  }
+}
+
+/// Options of a [`FieldMapping::KnnVector`] field.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnnVectorMapping {
+  dimension: u32,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  method: Option<KnnMethod>,
+}
+
+impl KnnVectorMapping {
+  /// Approximate k-NN method used to index the vectors
+  pub fn method(mut self, method: KnnMethod) -> Self {
+    self.method = Some(method);
+    self
+  }
+}
+
+/// Approximate k-NN method of a [`FieldMapping::KnnVector`] field, such as
+/// `hnsw`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnnMethod {
+  name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  space_type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  engine: Option<String>,
+  #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+  parameters: serde_json::Map<String, serde_json::Value>,
+}
+
+impl KnnMethod {
+  /// Creates a method from its name, such as `hnsw` or `ivf`
+  pub fn new<T: ToString>(name: T) -> Self {
+    Self {
+      name: name.to_string(),
+      space_type: None,
+      engine: None,
+      parameters: serde_json::Map::new(),
+    }
+  }
+
+  /// Distance function between vectors, such as `l2` or `cosinesimil`
+  pub fn space_type<T: ToString>(mut self, space_type: T) -> Self {
+    self.space_type = Some(space_type.to_string());
+    self
+  }
+
+  /// Library implementing the method, such as `lucene`, `faiss` or `nmslib`
+  pub fn engine<T: ToString>(mut self, engine: T) -> Self {
+    self.engine = Some(engine.to_string());
+    self
+  }
+
+  /// Sets a parameter of the method, such as `ef_construction` or `m`
+  pub fn parameter<T: ToString, V: Into<serde_json::Value>>(mut self, name: T, value: V) -> Self {
+    self.parameters.insert(name.to_string(), value.into());
+    self
+  }
+}
+
+macro_rules! impl_into_field_mapping {
+  ($($mapping:ident => $variant:ident),* $(,)?) => {
+    $(
+      impl From<$mapping> for FieldMapping {
+        fn from(value: $mapping) -> Self {
+          Self::$variant(value)
+        }
+      }
+    )*
+  };
+}
+
+impl_into_field_mapping!(
+  TextMapping => Text,
+  KeywordMapping => Keyword,
+  DateMapping => Date,
+  ObjectMapping => Object,
+  KnnVectorMapping => KnnVector,
+);
+
+impl From<NestedMapping> for FieldMapping {
+  fn from(value: NestedMapping) -> Self {
+    Self::Nested(value.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_text_field_with_analyzer() {
+    let mappings = Mappings::new().property(
+      "title",
+      FieldMapping::text()
+        .analyzer("english")
+        .search_analyzer("standard")
+        .field("raw", FieldMapping::keyword().ignore_above(256)),
+    );
+
+    assert_eq!(
+      serde_json::to_value(&mappings).unwrap(),
+      json!({
+        "properties": {
+          "title": {
+            "type": "text",
+            "analyzer": "english",
+            "search_analyzer": "standard",
+            "fields": { "raw": { "type": "keyword", "ignore_above": 256 } }
+          }
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn test_knn_vector_with_dimension() {
+    let mappings = Mappings::new()
+      .property(
+        "embedding",
+        FieldMapping::knn_vector(768).method(
+          KnnMethod::new("hnsw")
+            .space_type("cosinesimil")
+            .engine("lucene")
+            .parameter("m", 16),
+        ),
+      )
+      .property(
+        "chunks",
+        FieldMapping::nested().property("vector", FieldMapping::knn_vector(3)),
+      );
+
+    assert_eq!(
+      serde_json::to_value(&mappings).unwrap(),
+      json!({
+        "properties": {
+          "embedding": {
+            "type": "knn_vector",
+            "dimension": 768,
+            "method": {
+              "name": "hnsw",
+              "space_type": "cosinesimil",
+              "engine": "lucene",
+              "parameters": { "m": 16 }
+            }
+          },
+          "chunks": {
+            "type": "nested",
+            "properties": { "vector": { "type": "knn_vector", "dimension": 3 } }
+          }
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn test_into_index_template_mapping() {
+    let body: IndexTemplateMapping = Mappings::new()
+      .dynamic(false)
+      .property("released", FieldMapping::date().format("yyyy-MM-dd"))
+      .into();
+
+    assert_eq!(body.mappings["dynamic"], json!(false));
+    assert_eq!(
+      body.mappings["properties"],
+      json!({ "released": { "type": "date", "format": "yyyy-MM-dd" } })
+    );
+  }
+  #[test]
+  fn test_strict_dynamic_mapping() {
+    let mappings = Mappings::new()
+      .dynamic(DynamicMapping::Strict)
+      .property("meta", FieldMapping::object().dynamic(true))
+      .property("tags", FieldMapping::nested().dynamic(DynamicMapping::False));
+
+    assert_eq!(
+      serde_json::to_value(&mappings).unwrap(),
+      json!({
+        "dynamic": "strict",
+        "properties": {
+          "meta": { "type": "object", "dynamic": true },
+          "tags": { "type": "nested", "dynamic": false }
+        }
+      })
+    );
+  }
+}
@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use crate::{types::OpenSearchNameValue, Error, OsClient};
 mod builder;
+pub mod mappings;
 pub mod types;
 pub struct Indices<'a> {
   os_client: &'a OsClient,
@@ -40,7 +40,7 @@ mod builder_search;
 
 #[allow(unused_imports)]
 use client::{encode_path, encode_path_option_vec_string, RequestBuilderExt};
-pub use client::{ByteStream, Error, ResponseValue};
+pub use client::{ByteStream, Error, OpenSearchError, ResponseValue};
 #[allow(unused_imports)]
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::{de::DeserializeOwned, Serialize};
@@ -3747,6 +3747,45 @@ impl OsClient {
     Ok(result)
   }
 
+  /// Runs several searches in a single `_msearch` request.
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - The searches to run, each with its header.
+  ///
+  /// # Returns
+  ///
+  /// The result of each search, or the error it failed with, in the order of
+  /// the request.
+  #[cfg(feature = "search")]
+  pub async fn msearch_typed<T: DeserializeOwned + std::default::Default>(
+    &self,
+    request: &types::msearch::MultiSearch,
+  ) -> Result<types::msearch::MultiSearchResponse<T>, Error> {
+    let url = self.baseurl.join("_msearch")?;
+    let response = self
+      .client
+      .post(url)
+      .body(request.to_ndjson()?)
+      .header("Content-Type", "application/x-ndjson")
+      .send()
+      .await?;
+    match response.status().as_u16() {
+      200u16 => {
+        Ok(
+          ResponseValue::<types::msearch::MultiSearchResponse<T>>::from_response(response)
+            .await?
+            .into_inner(),
+        )
+      }
+      _ => {
+        Err(Error::UnexpectedResponse(
+          ReqwestResponse::from_response(response).await,
+        ))
+      }
+    }
+  }
+
   /// Searches for documents in the specified index and returns a stream of
   /// hits.
   ///
@@ -3755,7 +3794,7 @@ impl OsClient {
   /// * `index` - The name of the index to search in.
   /// * `query` - The query to execute.
   /// * `sort` - The sort criteria to use.
-  /// * `size` - The maximum number of hits to return.
+  /// * `size` - The number of hits fetched per request, at least 1.
   ///
   /// # Returns
   ///
@@ -3796,56 +3835,17 @@ impl OsClient {
       index: index.to_owned(),
       query: query.clone(),
       sort: sort.clone(),
-      size,
+      size: size.max(1),
     };
 
-    async fn stream_next<T: DeserializeOwned + std::default::Default>(
-      state: SearchAfterState,
-    ) -> Result<(Vec<types::Hit<T>>, SearchAfterState), Error> {
-      let mut body: Search = Search::new()
-        .size(state.size)
-        .query(state.query.clone())
-        .sort(state.sort.clone());
-
-      if let Some(search_after) = state.search_after.clone() {
-        body = body.search_after(search_after.clone());
-      }
-      let response = state
-        .client
-        .clone()
-        .search()
-        .index(&state.index)
-        .body(body)
-        .send::<T>()
-        .await?;
-      let hits = response.into_inner().hits.hits;
-      let next_state = SearchAfterState {
-        stop: (hits.len() as u64) < state.size,
-        search_after: hits.iter().last().and_then(|f| {
-          if let Some(last_sort) = f.sort.clone().unwrap().as_array() {
-            if last_sort.is_empty() {
-              None
-            } else {
-              Some(Terms::from(last_sort))
-            }
-          } else {
-            None
-          }
-        }),
-        ..state
-      };
-
-      Ok((hits, next_state))
-    }
-
-    let stream = stream::unfold(start_state, move |state| {
+    let stream = stream::unfold(start_state, move |mut state| {
       async move {
         if state.stop {
           None
         } else {
-          let result = stream_next::<T>(state).await;
+          let result = state.next_page::<T>().await;
           match result {
-            Ok((items, state)) => Some((stream::iter(items), state)),
+            Ok(items) => Some((stream::iter(items), state)),
             Err(_err) => None,
           }
         }
@@ -3854,6 +3854,217 @@ impl OsClient {
 
     Ok(stream.flatten())
   }
+
+  /// Pages through the documents matching a query in the specified index
+  /// using `search_after`, and returns a stream of pages.
+  ///
+  /// An `_id` tiebreaker is appended to `sort` unless it already sorts on
+  /// `_id` or `_shard_doc`, as paging with `search_after` requires every hit
+  /// to have unique sort values.
+  ///
+  /// # Arguments
+  ///
+  /// * `index` - The name of the index to search in.
+  /// * `query` - The query to execute.
+  /// * `sort` - The sort criteria to use.
+  /// * `size` - The number of hits per page, at least 1.
+  ///
+  /// # Returns
+  ///
+  /// A stream of non-empty pages of hits, ending after the first page with
+  /// fewer than `size` hits, a last hit without sort values, or the first
+  /// error.
+  #[cfg(feature = "search")]
+  pub fn search_after_stream<T: DeserializeOwned + std::default::Default + 'static>(
+    &self,
+    index: &str,
+    query: &Query,
+    sort: &SortCollection,
+    size: u64,
+  ) -> impl Stream<Item = Result<Vec<types::Hit<T>>, Error>> + 'static {
+    let start_state = SearchAfterState {
+      client: Arc::new(self.clone()),
+      stop: false,
+      search_after: None,
+      index: index.to_owned(),
+      query: query.clone(),
+      sort: with_tiebreaker(sort),
+      size: size.max(1),
+    };
+
+    stream::unfold(start_state, |mut state| {
+      async move {
+        if state.stop {
+          return None;
+        }
+        let page = state.next_page::<T>().await;
+        match &page {
+          Ok(hits) if hits.is_empty() => return None,
+          Ok(_) => {}
+          Err(_) => state.stop = true,
+        }
+        Some((page, state))
+      }
+    })
+  }
+
+  /// Scrolls through the documents matching a search in the specified index
+  /// and returns a stream of pages.
+  ///
+  /// # Arguments
+  ///
+  /// * `index` - The name of the index to search in.
+  /// * `search` - The search to execute, its `size` being the size of a page.
+  /// * `scroll` - How long to keep the scroll context alive between pages, such
+  ///   as `1m`.
+  ///
+  /// # Returns
+  ///
+  /// A stream of search results, one per page, ending after the last
+  /// non-empty page or the first error. The scroll context is cleared once
+  /// the last page has been read, or in the background if the stream is
+  /// dropped before.
+  #[cfg(feature = "search")]
+  pub fn scroll_stream<T: DeserializeOwned + std::default::Default + 'static>(
+    &self,
+    index: &str,
+    search: Search,
+    scroll: &str,
+  ) -> impl Stream<Item = Result<types::SearchResult<T>, Error>> + 'static {
+    let start_state = ScrollState {
+      context: ScrollContext {
+        client: Arc::new(self.clone()),
+        scroll_id: None,
+      },
+      index: index.to_owned(),
+      search: Some(search),
+      scroll: scroll.to_owned(),
+      stop: false,
+    };
+
+    stream::unfold(start_state, |mut state| {
+      async move {
+        if state.stop {
+          return None;
+        }
+
+        let client = state.context.client.clone();
+        let page = match (state.search.take(), state.context.scroll_id.clone()) {
+          (Some(search), _) => {
+            client
+              .search()
+              .index(&state.index)
+              .scroll(state.scroll.as_str())
+              .body(search)
+              .send::<T>()
+              .await
+              .map(ResponseValue::into_inner)
+          }
+          (None, Some(scroll_id)) => client.scroll_page::<T>(&state.scroll, &scroll_id).await,
+          (None, None) => return None,
+        };
+
+        match page {
+          Ok(page) => {
+            if page.scroll_id.is_some() {
+              state.context.scroll_id = page.scroll_id.clone();
+            }
+            if page.hits.hits.is_empty() {
+              state.context.clear().await;
+              return None;
+            }
+            Some((Ok(page), state))
+          }
+          Err(err) => {
+            state.stop = true;
+            Some((Err(err), state))
+          }
+        }
+      }
+    })
+  }
+
+  #[cfg(feature = "search")]
+  async fn scroll_page<T: DeserializeOwned + std::default::Default>(
+    &self,
+    scroll: &str,
+    scroll_id: &str,
+  ) -> Result<types::SearchResult<T>, Error> {
+    let url = self.baseurl.join("_search/scroll")?;
+    let body = serde_json::json!({ "scroll": scroll, "scroll_id": scroll_id });
+    let response = self.client.post(url).json(&body).send().await?;
+    match response.status().as_u16() {
+      200u16 => {
+        let response: ResponseValue<types::SearchResult<T>> = ResponseValue::from_response(response).await?;
+        Ok(response.into_inner())
+      }
+      _ => {
+        Err(Error::UnexpectedResponse(
+          ReqwestResponse::from_response(response).await,
+        ))
+      }
+    }
+  }
+
+  #[cfg(feature = "search")]
+  async fn clear_scroll_id(&self, scroll_id: &str) -> Result<(), Error> {
+    let url = self.baseurl.join("_search/scroll")?;
+    let body = serde_json::json!({ "scroll_id": [scroll_id] });
+    let response = self.client.delete(url).json(&body).send().await?;
+    match response.status().as_u16() {
+      200u16 | 404u16 => Ok(()),
+      _ => {
+        Err(Error::UnexpectedResponse(
+          ReqwestResponse::from_response(response).await,
+        ))
+      }
+    }
+  }
+}
+
+/// Represents the state of a scroll operation.
+#[cfg(feature = "search")]
+struct ScrollState {
+  context: ScrollContext,
+  index: String,
+  search: Option<Search>,
+  scroll: String,
+  stop: bool,
+}
+
+/// Scroll context that is cleared when dropped, so that a scroll stream
+/// dropped before its end doesn't keep the context alive until it expires.
+#[cfg(feature = "search")]
+struct ScrollContext {
+  client: Arc<OsClient>,
+  scroll_id: Option<String>,
+}
+
+#[cfg(feature = "search")]
+impl ScrollContext {
+  async fn clear(&mut self) {
+    if let Some(scroll_id) = self.scroll_id.take() {
+      if let Err(err) = self.client.clear_scroll_id(&scroll_id).await {
+        info!("failed to clear scroll context: {err}");
+      }
+    }
+  }
+}
+
+#[cfg(feature = "search")]
+impl Drop for ScrollContext {
+  fn drop(&mut self) {
+    if let Some(scroll_id) = self.scroll_id.take() {
+      if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        let client = self.client.clone();
+        handle.spawn(async move {
+          if let Err(err) = client.clear_scroll_id(&scroll_id).await {
+            info!("failed to clear scroll context: {err}");
+          }
+        });
+      }
+    }
+  }
 }
 
 /// Represents the state of a search operation that uses the "search after"
@@ -3869,6 +4080,64 @@ struct SearchAfterState {
   search_after: Option<Terms>,
 }
 
+#[cfg(feature = "search")]
+impl SearchAfterState {
+  /// Fetches the page after `search_after`, and moves `search_after` to its
+  /// last hit.
+  async fn next_page<T: DeserializeOwned + std::default::Default>(&mut self) -> Result<Vec<types::Hit<T>>, Error> {
+    let mut body: Search = Search::new()
+      .size(self.size)
+      .query(self.query.clone())
+      .sort(self.sort.clone());
+
+    if let Some(search_after) = self.search_after.clone() {
+      body = body.search_after(search_after);
+    }
+    let response = self
+      .client
+      .clone()
+      .search()
+      .index(&self.index)
+      .body(body)
+      .send::<T>()
+      .await?;
+    let hits = response.into_inner().hits.hits;
+    self.search_after = hits.iter().last().and_then(|f| {
+      f.sort
+        .as_ref()
+        .filter(|last_sort| !last_sort.is_empty())
+        .map(Terms::from)
+    });
+    // Without sort values on the last hit, the next request would start over
+    // from the first page
+    self.stop = hits.is_empty() || (hits.len() as u64) < self.size || self.search_after.is_none();
+
+    Ok(hits)
+  }
+}
+
+/// Appends an `_id` tiebreaker to `sort` unless it already sorts on `_id` or
+/// `_shard_doc`, so that hits sharing the same sort values are neither
+/// skipped nor repeated between pages.
+#[cfg(feature = "search")]
+fn with_tiebreaker(sort: &SortCollection) -> SortCollection {
+  const TIEBREAKERS: [&str; 2] = ["_id", "_shard_doc"];
+
+  let has_tiebreaker = sort.clone().into_iter().any(|sort| {
+    match serde_json::to_value(sort) {
+      Ok(serde_json::Value::String(field)) => TIEBREAKERS.contains(&field.as_str()),
+      Ok(serde_json::Value::Object(fields)) => fields.keys().any(|field| TIEBREAKERS.contains(&field.as_str())),
+      _ => false,
+    }
+  });
+
+  if has_tiebreaker {
+    sort.clone()
+  } else {
+    sort.clone().ascending("_id")
+  }
+}
+
 pub mod prelude {
   pub use self::super::OsClient;
 }
@@ -3892,4 +4161,236 @@ mod tests {
     let decoded: types::DocumentDeleteResponse = load_entity("document_delete.response.json");
     assert_eq!(decoded.id, String::from("MzcIJX8BA7mbufL6DOwl"));
   }
+
+  /// Transport answering requests with canned responses, in order, and
+  /// recording the method, path and body of every request.
+  #[derive(Clone, Default)]
+  struct MockTransport {
+    responses: Arc<Mutex<std::collections::VecDeque<serde_json::Value>>>,
+    requests: Arc<Mutex<Vec<(String, String, Option<serde_json::Value>)>>>,
+  }
+
+  impl MockTransport {
+    fn new<I: IntoIterator<Item = serde_json::Value>>(responses: I) -> Self {
+      Self {
+        responses: Arc::new(Mutex::new(responses.into_iter().collect())),
+        requests: Arc::default(),
+      }
+    }
+
+    fn client(&self) -> OsClient {
+      OsClient {
+        baseurl: Arc::new(Url::parse("http://localhost:9200").unwrap()),
+        client: reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+          .with(self.clone())
+          .build(),
+        bulker: Arc::new(Mutex::new(String::new())),
+        bulker_size: Arc::new(Mutex::new(0)),
+        max_bulk_size: 200,
+      }
+    }
+
+    fn requests(&self) -> Vec<(String, String, Option<serde_json::Value>)> {
+      self.requests.lock().unwrap().clone()
+    }
+  }
+
+  #[async_trait::async_trait]
+  impl reqwest_middleware::Middleware for MockTransport {
+    async fn handle(
+      &self,
+      req: reqwest::Request,
+      _extensions: &mut http::Extensions,
+      _next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+      let path = match req.url().query() {
+        Some(query) => format!("{}?{}", req.url().path(), query),
+        None => req.url().path().to_string(),
+      };
+      let body = req
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| serde_json::from_slice(body).unwrap());
+      self
+        .requests
+        .lock()
+        .unwrap()
+        .push((req.method().to_string(), path, body));
+
+      let response = self.responses.lock().unwrap().pop_front().unwrap_or_default();
+      Ok(reqwest::Response::from(http::Response::new(response.to_string())))
+    }
+  }
+
+  #[cfg(feature = "search")]
+  fn page(scroll_id: &str, ids: &[&str]) -> serde_json::Value {
+    let hits: Vec<serde_json::Value> = ids
+      .iter()
+      .map(|id| serde_json::json!({ "_index": "movies", "_id": id, "_source": { "title": id } }))
+      .collect();
+    serde_json::json!({ "_scroll_id": scroll_id, "took": 1, "hits": { "hits": hits } })
+  }
+
+  #[cfg(feature = "search")]
+  #[tokio::test]
+  async fn test_scroll_stream_pages() {
+    let transport = MockTransport::new([
+      page("scroll-1", &["1", "2"]),
+      page("scroll-2", &["3"]),
+      page("scroll-2", &[]),
+      serde_json::json!({ "succeeded": true, "num_freed": 1 }),
+    ]);
+
+    let pages: Vec<types::SearchResult<serde_json::Value>> = transport
+      .client()
+      .scroll_stream("movies", Search::new().size(2), "1m")
+      .map(Result::unwrap)
+      .collect()
+      .await;
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].hits.hits.len(), 2);
+    assert_eq!(pages[1].hits.hits[0].id, "3");
+    assert_eq!(
+      transport.requests(),
+      [
+        (
+          "POST".to_string(),
+          "/movies/_search?scroll=1m".to_string(),
+          Some(serde_json::json!({ "size": 2 }))
+        ),
+        (
+          "POST".to_string(),
+          "/_search/scroll".to_string(),
+          Some(serde_json::json!({ "scroll": "1m", "scroll_id": "scroll-1" }))
+        ),
+        (
+          "POST".to_string(),
+          "/_search/scroll".to_string(),
+          Some(serde_json::json!({ "scroll": "1m", "scroll_id": "scroll-2" }))
+        ),
+        (
+          "DELETE".to_string(),
+          "/_search/scroll".to_string(),
+          Some(serde_json::json!({ "scroll_id": ["scroll-2"] }))
+        ),
+      ]
+    );
+  }
+
+  #[cfg(feature = "search")]
+  #[tokio::test]
+  async fn test_scroll_stream_clears_when_dropped() {
+    let transport = MockTransport::new([page("scroll-1", &["1", "2"]), page("scroll-2", &["3"])]);
+
+    let first: Vec<_> = transport
+      .client()
+      .scroll_stream::<serde_json::Value>("movies", Search::new().size(2), "1m")
+      .take(1)
+      .collect()
+      .await;
+    assert_eq!(first.len(), 1);
+
+    for _ in 0..10 {
+      if transport.requests().len() == 2 {
+        break;
+      }
+      tokio::task::yield_now().await;
+    }
+    assert_eq!(
+      transport.requests().last(),
+      Some(&(
+        "DELETE".to_string(),
+        "/_search/scroll".to_string(),
+        Some(serde_json::json!({ "scroll_id": ["scroll-1"] }))
+      ))
+    );
+  }
+
+  #[cfg(feature = "search")]
+  #[tokio::test]
+  async fn test_search_after_stream_pages() {
+    let hit = |id: &str, year: u64| serde_json::json!({ "_index": "movies", "_id": id, "sort": [year, id] });
+    let transport = MockTransport::new([
+      serde_json::json!({ "took": 1, "hits": { "hits": [hit("1", 1999), hit("2", 2004)] } }),
+      serde_json::json!({ "took": 1, "hits": { "hits": [hit("3", 2011)] } }),
+    ]);
+
+    let pages: Vec<Vec<types::Hit<serde_json::Value>>> = transport
+      .client()
+      .search_after_stream(
+        "movies",
+        &Query::match_all().into(),
+        &SortCollection::new().ascending("year"),
+        2,
+      )
+      .map(Result::unwrap)
+      .collect()
+      .await;
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].len(), 2);
+    assert_eq!(pages[1][0].id, "3");
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    let first = requests[0].2.as_ref().unwrap();
+    assert_eq!(
+      first["sort"],
+      serde_json::json!([{ "year": { "order": "asc" } }, { "_id": { "order": "asc" } }])
+    );
+    assert!(first.get("search_after").is_none());
+    let second = requests[1].2.as_ref().unwrap();
+    assert_eq!(second["search_after"], serde_json::json!([2004, "2"]));
+  }
+
+  #[cfg(feature = "search")]
+  #[tokio::test]
+  async fn test_search_after_stream_stops_on_empty_page() {
+    let hit = |id: &str| serde_json::json!({ "_index": "movies", "_id": id, "sort": [id] });
+    let transport = MockTransport::new([
+      serde_json::json!({ "took": 1, "hits": { "hits": [hit("1"), hit("2")] } }),
+      serde_json::json!({ "took": 1, "hits": { "hits": [] } }),
+    ]);
+
+    let pages: Vec<Vec<types::Hit<serde_json::Value>>> = transport
+      .client()
+      .search_after_stream("movies", &Query::match_all().into(), &SortCollection::new(), 2)
+      .map(Result::unwrap)
+      .collect()
+      .await;
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(transport.requests().len(), 2);
+  }
+
+  #[cfg(feature = "search")]
+  #[tokio::test]
+  async fn test_search_after_stream_clamps_size_and_stops_without_sort() {
+    let transport = MockTransport::new([serde_json::json!({
+      "took": 1,
+      "hits": { "hits": [{ "_index": "movies", "_id": "1" }] }
+    })]);
+
+    let pages: Vec<Vec<types::Hit<serde_json::Value>>> = transport
+      .client()
+      .search_after_stream("movies", &Query::match_all().into(), &SortCollection::new(), 0)
+      .map(Result::unwrap)
+      .collect()
+      .await;
+
+    assert_eq!(pages.len(), 1);
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].2.as_ref().unwrap()["size"], 1);
+  }
+
+  #[cfg(feature = "search")]
+  #[test]
+  fn test_with_tiebreaker_keeps_existing() {
+    let sort = SortCollection::new()
+      .ascending("year")
+      .field(opensearch_dsl::FieldSort::ascending("_shard_doc"));
+    assert_eq!(with_tiebreaker(&sort), sort);
+  }
 }
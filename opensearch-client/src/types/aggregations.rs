@@ -0,0 +1,659 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+
+use super::{
+  buckets::{
+    DateHistogramAggResult, DateRangeAggResult, FilterAggResult, FiltersAggResult, GeoGridAggregation, NestedAggResult,
+    RangeAggResult, TermsAggResult,
+  },
+  Aggregations, Hits, SubAggregations,
+};
+
+/// Named aggregation results of a search response.
+///
+/// Results are kept as returned by OpenSearch and parsed into the typed result
+/// of their aggregation on access, since the response itself doesn't say
+/// which aggregation produced each entry.
+///
+/// ```
+/// # use opensearch_client::types::AggregationResults;
+/// let aggregations: AggregationResults = serde_json::from_value(serde_json::json!({
+///   "genres": { "buckets": [{ "key": "rock", "doc_count": 10 }] },
+///   "authors": { "value": 3 }
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(aggregations.terms("genres").unwrap().buckets[0].doc_count, 10);
+/// assert_eq!(aggregations.cardinality("authors").unwrap().value, Some(3.0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AggregationResults(pub BTreeMap<String, serde_json::Value>);
+
+// Accessors shared by `AggregationResults` and `SubAggregations`, which both
+// keep results as returned by OpenSearch
+macro_rules! aggregation_accessors {
+  () => {
+    /// Result of the aggregation `name` as returned by OpenSearch
+    pub fn get_raw(&self, name: &str) -> Option<&serde_json::Value> {
+      self.0.get(name)
+    }
+
+    /// Parses the aggregation result `name` into `T`, returning `None` if it
+    /// is missing or doesn't have the shape of `T`
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+      self.get_raw(name).and_then(|value| T::deserialize(value).ok())
+    }
+
+    /// Names of all aggregation results
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+      self.0.keys().map(String::as_str)
+    }
+
+    /// Result of the `terms` aggregation `name`
+    pub fn terms(&self, name: &str) -> Option<TermsAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `date_histogram` aggregation `name`
+    pub fn date_histogram(&self, name: &str) -> Option<DateHistogramAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `range` aggregation `name`
+    pub fn range(&self, name: &str) -> Option<RangeAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `date_range` aggregation `name`
+    pub fn date_range(&self, name: &str) -> Option<DateRangeAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `filters` aggregation `name`
+    pub fn filters(&self, name: &str) -> Option<FiltersAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `filter` aggregation `name`
+    pub fn filter(&self, name: &str) -> Option<FilterAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `nested` aggregation `name`
+    pub fn nested(&self, name: &str) -> Option<NestedAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `reverse_nested` aggregation `name`
+    pub fn reverse_nested(&self, name: &str) -> Option<NestedAggResult> {
+      self.get(name)
+    }
+
+    /// Result of the `geohash_grid` aggregation `name`
+    pub fn geohash_grid(&self, name: &str) -> Option<GeoGridAggregation> {
+      self.get(name)
+    }
+
+    /// Result of the `geotile_grid` aggregation `name`
+    pub fn geotile_grid(&self, name: &str) -> Option<GeoGridAggregation> {
+      self.get(name)
+    }
+
+    /// Result of the `stats` aggregation `name`
+    pub fn stats(&self, name: &str) -> Option<StatsResult> {
+      self.get(name)
+    }
+
+    /// Result of the `extended_stats` aggregation `name`
+    pub fn extended_stats(&self, name: &str) -> Option<ExtendedStatsResult> {
+      self.get(name)
+    }
+
+    /// Result of the `percentiles` aggregation `name`
+    pub fn percentiles(&self, name: &str) -> Option<PercentilesResult> {
+      self.get(name)
+    }
+
+    /// Result of the `percentile_ranks` aggregation `name`
+    pub fn percentile_ranks(&self, name: &str) -> Option<PercentilesResult> {
+      self.get(name)
+    }
+
+    /// Result of the `top_hits` aggregation `name`, with each hit's `_source`
+    /// parsed into `T`
+    pub fn top_hits<T: DeserializeOwned + Default>(&self, name: &str) -> Option<TopHitsResult<T>> {
+      self.get(name)
+    }
+
+    /// Result of the `avg` aggregation `name`
+    pub fn avg(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+
+    /// Result of the `sum` aggregation `name`
+    pub fn sum(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+
+    /// Result of the `min` aggregation `name`
+    pub fn min(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+
+    /// Result of the `max` aggregation `name`
+    pub fn max(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+
+    /// Result of the `value_count` aggregation `name`
+    pub fn value_count(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+
+    /// Result of the `cardinality` aggregation `name`
+    pub fn cardinality(&self, name: &str) -> Option<SingleMetric> {
+      self.get(name)
+    }
+  };
+}
+
+impl AggregationResults {
+  aggregation_accessors!();
+}
+
+/// Converts decoded [`Aggregations`] back into results that can be read with
+/// the typed accessors. Fields the enum doesn't keep, such as the
+/// `value_as_string` of a metric, are not available.
+impl From<&HashMap<String, Aggregations>> for AggregationResults {
+  fn from(aggregations: &HashMap<String, Aggregations>) -> Self {
+    AggregationResults(
+      aggregations
+        .iter()
+        .filter_map(|(name, aggregation)| Some((name.clone(), serde_json::to_value(aggregation).ok()?)))
+        .collect(),
+    )
+  }
+}
+
+impl SubAggregations {
+  aggregation_accessors!();
+}
+
+/// Result of a single-value metric aggregation, such as `avg`, `sum`, `min`,
+/// `max`, `value_count` or `cardinality`.
+///
+/// `value` is `None` when no document had a value to aggregate, and
+/// `value_as_string` is only returned for fields with a `format`, such as
+/// dates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SingleMetric {
+  #[serde(default)]
+  pub value: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub value_as_string: Option<String>,
+}
+
+/// Result of a `stats` aggregation.
+///
+/// `min`, `max` and `avg` are `None` when no document matched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsResult {
+  pub count: u64,
+  #[serde(default)]
+  pub min: Option<f64>,
+  #[serde(default)]
+  pub max: Option<f64>,
+  #[serde(default)]
+  pub avg: Option<f64>,
+  pub sum: f64,
+}
+
+/// Result of an `extended_stats` aggregation.
+///
+/// Every statistic besides `count` and `sum` is `None` when no document
+/// matched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtendedStatsResult {
+  pub count: u64,
+  #[serde(default)]
+  pub min: Option<f64>,
+  #[serde(default)]
+  pub max: Option<f64>,
+  #[serde(default)]
+  pub avg: Option<f64>,
+  pub sum: f64,
+  #[serde(default)]
+  pub sum_of_squares: Option<f64>,
+  #[serde(default)]
+  pub variance: Option<f64>,
+  #[serde(default)]
+  pub std_deviation: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub std_deviation_bounds: Option<StdDeviationBounds>,
+}
+
+/// Interval of `sigma` standard deviations around the average of an
+/// `extended_stats` aggregation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StdDeviationBounds {
+  #[serde(default)]
+  pub upper: Option<f64>,
+  #[serde(default)]
+  pub lower: Option<f64>,
+}
+
+/// Result of a `percentiles` or `percentile_ranks` aggregation.
+///
+/// Both the default keyed form, `{"values": {"99.0": 10.0}}`, and the
+/// `keyed: false` form, `{"values": [{"key": 99.0, "value": 10.0}]}`, are
+/// read into `values`, ordered by key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PercentilesResult {
+  #[serde(deserialize_with = "deserialize_percentiles")]
+  pub values: Vec<Percentile>,
+}
+
+impl PercentilesResult {
+  /// Value at the percentile, or percentile rank, `key`
+  pub fn value(&self, key: f64) -> Option<f64> {
+    self.values.iter().find(|p| p.key == key).and_then(|p| p.value)
+  }
+}
+
+/// Single entry of a [`PercentilesResult`].
+///
+/// `key` is the requested percentile for a `percentiles` aggregation and the
+/// requested value for a `percentile_ranks` aggregation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Percentile {
+  pub key: f64,
+  #[serde(default)]
+  pub value: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub value_as_string: Option<String>,
+}
+
+fn deserialize_percentiles<'de, D>(deserializer: D) -> Result<Vec<Percentile>, D::Error>
+where
+  D: Deserializer<'de>, {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Values {
+    Keyed(BTreeMap<String, serde_json::Value>),
+    Array(Vec<Percentile>),
+  }
+
+  let mut percentiles = match Values::deserialize(deserializer)? {
+    Values::Array(percentiles) => percentiles,
+    Values::Keyed(map) => {
+      map
+        .iter()
+        .filter(|(key, _)| !key.ends_with("_as_string"))
+        .map(|(key, value)| {
+          Ok(Percentile {
+            key: key
+              .parse()
+              .map_err(|_| serde::de::Error::custom(format!("invalid percentile key `{key}`")))?,
+            value: value.as_f64(),
+            value_as_string: map
+              .get(&format!("{key}_as_string"))
+              .and_then(|v| v.as_str())
+              .map(str::to_string),
+          })
+        })
+        .collect::<Result<Vec<_>, D::Error>>()?
+    }
+  };
+
+  percentiles.sort_by(|a, b| a.key.total_cmp(&b.key));
+  Ok(percentiles)
+}
+
+/// Result of a `top_hits` aggregation, holding the same `hits` object as a
+/// search response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + Default"))]
+pub struct TopHitsResult<T> {
+  pub hits: Hits<T>,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_decode_terms_and_cardinality() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "genres": {
+        "doc_count_error_upper_bound": 0,
+        "sum_other_doc_count": 0,
+        "buckets": [
+          { "key": "rock", "doc_count": 10, "authors": { "value": 4 } },
+          { "key": "jazz", "doc_count": 3 }
+        ]
+      },
+      "authors": { "value": 7 }
+    }))
+    .unwrap();
+
+    let genres = decoded.terms("genres").unwrap();
+    assert_eq!(genres.buckets.len(), 2);
+    assert_eq!(genres.buckets[0].key, json!("rock"));
+    assert_eq!(genres.buckets[0].doc_count, 10);
+    assert_eq!(genres.buckets[0].aggregations.avg("authors").unwrap().value, Some(4.0));
+    assert_eq!(genres.buckets[1].key, json!("jazz"));
+    assert!(genres.buckets[1].aggregations.is_empty());
+
+    assert_eq!(decoded.cardinality("authors").unwrap().value, Some(7.0));
+  }
+
+  #[test]
+  fn test_decode_filter() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "t_shirts": { "doc_count": 3, "avg_price": { "value": 128.33333333333334 } }
+    }))
+    .unwrap();
+
+    let t_shirts = decoded.filter("t_shirts").unwrap();
+    assert_eq!(t_shirts.doc_count, 3);
+    assert_eq!(
+      t_shirts.aggregations.avg("avg_price").unwrap().value,
+      Some(128.33333333333334)
+    );
+  }
+
+  #[test]
+  fn test_decode_nested() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "comments": {
+        "doc_count": 4,
+        "top_authors": {
+          "doc_count_error_upper_bound": 0,
+          "sum_other_doc_count": 0,
+          "buckets": [{ "key": "kimchy", "doc_count": 3 }, { "key": "jdoe", "doc_count": 1 }]
+        }
+      }
+    }))
+    .unwrap();
+
+    let comments = decoded.nested("comments").unwrap();
+    assert_eq!(comments.doc_count, 4);
+    let top_authors = comments.aggregations.terms("top_authors").unwrap();
+    assert_eq!(top_authors.buckets.len(), 2);
+    assert_eq!(top_authors.buckets[0].key, json!("kimchy"));
+    assert_eq!(top_authors.buckets[0].doc_count, 3);
+  }
+
+  #[test]
+  fn test_navigate_sub_aggregations() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "genres": {
+        "doc_count_error_upper_bound": 0,
+        "sum_other_doc_count": 0,
+        "buckets": [{
+          "key": "rock",
+          "doc_count": 5,
+          "per_month": {
+            "buckets": [
+              {
+                "key_as_string": "2023-01-01",
+                "key": 1672531200000i64,
+                "doc_count": 2,
+                "avg_price": { "value": 12.5 }
+              },
+              {
+                "key_as_string": "2023-02-01",
+                "key": 1675209600000i64,
+                "doc_count": 3,
+                "avg_price": { "value": null }
+              }
+            ]
+          }
+        }]
+      }
+    }))
+    .unwrap();
+
+    let rock = &decoded.terms("genres").unwrap().buckets[0];
+    assert_eq!(rock.aggregations.names().collect::<Vec<_>>(), ["per_month"]);
+
+    let per_month = rock.aggregations.date_histogram("per_month").unwrap();
+    assert_eq!(per_month.buckets[0].key_as_string.as_deref(), Some("2023-01-01"));
+    assert_eq!(
+      per_month.buckets[0].aggregations.avg("avg_price").unwrap().value,
+      Some(12.5)
+    );
+    assert_eq!(per_month.buckets[1].aggregations.avg("avg_price").unwrap().value, None);
+    assert_eq!(per_month.buckets[1].aggregations.get_raw("missing"), None);
+  }
+
+  #[test]
+  fn test_navigate_filter_under_bucket() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "genres": {
+        "doc_count_error_upper_bound": 0,
+        "sum_other_doc_count": 0,
+        "buckets": [{
+          "key": "drama",
+          "doc_count": 10,
+          "recent": {
+            "doc_count": 4,
+            "avg_rating": { "value": 7.5 },
+            "latest": { "value": 1494288000000.0, "value_as_string": "2017-05-09" }
+          }
+        }]
+      }
+    }))
+    .unwrap();
+
+    let drama = &decoded.terms("genres").unwrap().buckets[0];
+    let recent = drama.aggregations.filter("recent").unwrap();
+    assert_eq!(recent.doc_count, 4);
+    assert_eq!(recent.aggregations.avg("avg_rating").unwrap().value, Some(7.5));
+    assert_eq!(
+      recent.aggregations.max("latest").unwrap().value_as_string.as_deref(),
+      Some("2017-05-09")
+    );
+    assert_eq!(drama.aggregations.get_raw("recent").unwrap()["doc_count"], json!(4));
+  }
+
+  #[test]
+  fn test_decode_geo_grids() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "cells": {
+        "buckets": [
+          { "key": "u17", "doc_count": 3, "avg_price": { "value": 12.5 } },
+          { "key": "u09", "doc_count": 1, "avg_price": { "value": 20.0 } }
+        ]
+      },
+      "tiles": { "buckets": [{ "key": "8/131/84", "doc_count": 4 }] }
+    }))
+    .unwrap();
+
+    let cells = decoded.geohash_grid("cells").unwrap();
+    assert_eq!(cells.buckets.len(), 2);
+    assert_eq!(cells.buckets[0].key, "u17");
+    assert_eq!(
+      cells.buckets[0].aggregations.avg("avg_price").unwrap().value,
+      Some(12.5)
+    );
+
+    let tiles = decoded.geotile_grid("tiles").unwrap();
+    assert_eq!(tiles.buckets[0].key, "8/131/84");
+    assert_eq!(tiles.buckets[0].doc_count, 4);
+  }
+
+  #[test]
+  fn test_decode_single_metrics() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "avg_price": { "value": null },
+      "latest": { "value": 1494288000000.0, "value_as_string": "2017-05-09T00:00:00.000Z" },
+      "products": { "value": 12 }
+    }))
+    .unwrap();
+
+    let avg_price = decoded.avg("avg_price").unwrap();
+    assert_eq!(avg_price.value, None);
+    assert_eq!(avg_price.value_as_string, None);
+
+    let latest = decoded.max("latest").unwrap();
+    assert_eq!(latest.value, Some(1494288000000.0));
+    assert_eq!(latest.value_as_string.as_deref(), Some("2017-05-09T00:00:00.000Z"));
+
+    assert_eq!(decoded.cardinality("products").unwrap().value, Some(12.0));
+  }
+
+  #[test]
+  fn test_decode_stats() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "grades": { "count": 2, "min": 50.0, "max": 100.0, "avg": 75.0, "sum": 150.0 },
+      "empty": { "count": 0, "min": null, "max": null, "avg": null, "sum": 0.0 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      decoded.stats("grades"),
+      Some(StatsResult {
+        count: 2,
+        min: Some(50.0),
+        max: Some(100.0),
+        avg: Some(75.0),
+        sum: 150.0,
+      })
+    );
+
+    let empty = decoded.stats("empty").unwrap();
+    assert_eq!(empty.count, 0);
+    assert_eq!(empty.avg, None);
+  }
+
+  #[test]
+  fn test_decode_extended_stats() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "grades": {
+        "count": 2,
+        "min": 50.0,
+        "max": 100.0,
+        "avg": 75.0,
+        "sum": 150.0,
+        "sum_of_squares": 12500.0,
+        "variance": 625.0,
+        "std_deviation": 25.0,
+        "std_deviation_bounds": { "upper": 125.0, "lower": 25.0 }
+      }
+    }))
+    .unwrap();
+
+    let grades = decoded.extended_stats("grades").unwrap();
+    assert_eq!(grades.count, 2);
+    assert_eq!(grades.sum_of_squares, Some(12500.0));
+    assert_eq!(grades.variance, Some(625.0));
+    assert_eq!(grades.std_deviation, Some(25.0));
+    assert_eq!(
+      grades.std_deviation_bounds,
+      Some(StdDeviationBounds {
+        upper: Some(125.0),
+        lower: Some(25.0),
+      })
+    );
+  }
+
+  #[test]
+  fn test_decode_keyed_percentiles() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "load_time": {
+        "values": {
+          "1.0": 5.0,
+          "25.0": 38.75,
+          "5.0": 25.0,
+          "99.0": null
+        }
+      }
+    }))
+    .unwrap();
+
+    let load_time = decoded.percentiles("load_time").unwrap();
+    assert_eq!(
+      load_time.values.iter().map(|p| p.key).collect::<Vec<_>>(),
+      [1.0, 5.0, 25.0, 99.0]
+    );
+    assert_eq!(load_time.value(25.0), Some(38.75));
+    assert_eq!(load_time.value(99.0), None);
+  }
+
+  #[test]
+  fn test_decode_unkeyed_percentile_ranks() {
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "load_time_ranks": {
+        "values": [
+          { "key": 500.0, "value": 55.0 },
+          { "key": 600.0, "value": 64.0, "value_as_string": "64%" }
+        ]
+      }
+    }))
+    .unwrap();
+
+    let ranks = decoded.percentile_ranks("load_time_ranks").unwrap();
+    assert_eq!(ranks.value(500.0), Some(55.0));
+    assert_eq!(
+      ranks.values[1],
+      Percentile {
+        key: 600.0,
+        value: Some(64.0),
+        value_as_string: Some("64%".to_string()),
+      }
+    );
+  }
+
+  #[test]
+  fn test_decode_top_hits() {
+    #[derive(Debug, Default, PartialEq, Deserialize)]
+    struct Sale {
+      date: String,
+      price: u64,
+    }
+
+    let decoded: AggregationResults = serde_json::from_value(json!({
+      "top_sales": {
+        "hits": {
+          "total": { "value": 3, "relation": "eq" },
+          "max_score": null,
+          "hits": [
+            {
+              "_index": "sales",
+              "_id": "AVnNBmauCQpcRyxw6ChK",
+              "_score": null,
+              "_source": { "date": "2015/03/01 00:00:00", "price": 200 },
+              "sort": [1425168000000u64]
+            }
+          ]
+        }
+      }
+    }))
+    .unwrap();
+
+    let top_sales = decoded.top_hits::<Sale>("top_sales").unwrap();
+    assert_eq!(top_sales.hits.total.unwrap().value, Some(3));
+    assert_eq!(top_sales.hits.hits.len(), 1);
+    assert_eq!(top_sales.hits.hits[0].id, "AVnNBmauCQpcRyxw6ChK");
+    assert_eq!(
+      top_sales.hits.hits[0].source,
+      Some(Sale {
+        date: "2015/03/01 00:00:00".to_string(),
+        price: 200,
+      })
+    );
+  }
+
+  #[test]
+  fn test_missing_or_mismatched_aggregation() {
+    let decoded: AggregationResults = serde_json::from_value(json!({ "authors": { "value": 7 } })).unwrap();
+
+    assert_eq!(decoded.terms("missing"), None);
+    assert_eq!(decoded.terms("authors"), None);
+  }
+}
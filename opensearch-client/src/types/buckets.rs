@@ -1,15 +1,36 @@
-use serde::{Deserialize, Serialize};
+use std::{fmt, marker::PhantomData};
 
-use super::SubAggregations;
+use serde::{
+  de::{MapAccess, SeqAccess, Visitor},
+  Deserialize, Deserializer, Serialize,
+};
+
+use super::{aggregations::SingleMetric, SubAggregations};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TermsBucket {
   pub key: serde_json::Value,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub key_as_string: Option<String>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
+/// Response of a `terms` aggregation.
+///
+/// `doc_count_error_upper_bound` is the worst case count of documents of a
+/// term missing from `buckets`, and `sum_other_doc_count` the count of
+/// documents of every term left out of `buckets`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermsAggResult {
+  #[serde(default)]
+  pub doc_count_error_upper_bound: i64,
+  #[serde(default)]
+  pub sum_other_doc_count: u64,
+  pub buckets: Vec<TermsBucket>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RangeBucket {
   #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -19,7 +40,7 @@ pub struct RangeBucket {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub to: Option<f64>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
@@ -28,23 +49,43 @@ pub struct DateRangeBucket {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub key: Option<String>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub from: Option<String>,
+  pub from: Option<f64>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub from_as_string: Option<String>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub to: Option<String>,
+  pub to: Option<f64>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub to_as_string: Option<String>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
+/// Response of a `range` aggregation.
+///
+/// Buckets of a `keyed` range aggregation are read in response order, with
+/// their `key` taken from the response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RangeAggResult {
+  #[serde(deserialize_with = "deserialize_keyed_buckets")]
+  pub buckets: Vec<RangeBucket>,
+}
+
+/// Response of a `date_range` aggregation.
+///
+/// Buckets of a `keyed` date range aggregation are read in response order,
+/// with their `key` taken from the response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateRangeAggResult {
+  #[serde(deserialize_with = "deserialize_keyed_buckets")]
+  pub buckets: Vec<DateRangeBucket>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HistogramBucket {
   pub key: f64,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
@@ -54,17 +95,23 @@ pub struct DateHistogramBucket {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub key_as_string: Option<String>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
+/// Response of a `date_histogram` aggregation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateHistogramAggResult {
+  pub buckets: Vec<DateHistogramBucket>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GeoDistanceBucket {
   pub key: String,
   pub from: Option<f64>,
   pub to: Option<f64>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
@@ -73,13 +120,425 @@ pub struct FiltersBucket {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub key: Option<String>,
   pub doc_count: u64,
-  #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+  #[serde(flatten, default)]
+  pub aggregations: SubAggregations,
+}
+
+/// Response of a `filters` aggregation.
+///
+/// Buckets of named filters are read in response order, with their `key` set
+/// to the name of the filter. Buckets of anonymous filters have no `key`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FiltersAggResult {
+  #[serde(deserialize_with = "deserialize_keyed_buckets")]
+  pub buckets: Vec<FiltersBucket>,
+}
+
+/// Bucket that can be returned in a map keyed by its `key`.
+trait KeyedBucket {
+  fn set_key(&mut self, key: String);
+}
+
+macro_rules! impl_keyed_bucket {
+  ($($bucket:ty),*) => {
+    $(
+      impl KeyedBucket for $bucket {
+        fn set_key(&mut self, key: String) {
+          self.key = Some(key);
+        }
+      }
+    )*
+  };
+}
+
+impl_keyed_bucket!(RangeBucket, DateRangeBucket, FiltersBucket);
+
+/// Reads buckets returned either as an array or as a map keyed by bucket key.
+fn deserialize_keyed_buckets<'de, D, B>(deserializer: D) -> Result<Vec<B>, D::Error>
+where
+  D: Deserializer<'de>,
+  B: Deserialize<'de> + KeyedBucket, {
+  struct BucketsVisitor<B>(PhantomData<B>);
+
+  impl<'de, B> Visitor<'de> for BucketsVisitor<B>
+  where
+    B: Deserialize<'de> + KeyedBucket,
+  {
+    type Value = Vec<B>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+      formatter.write_str("an array or a map of buckets")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+      A: SeqAccess<'de>, {
+      let mut buckets = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+      while let Some(bucket) = seq.next_element()? {
+        buckets.push(bucket);
+      }
+      Ok(buckets)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+      A: MapAccess<'de>, {
+      let mut buckets = Vec::with_capacity(map.size_hint().unwrap_or_default());
+      while let Some((key, mut bucket)) = map.next_entry::<String, B>()? {
+        bucket.set_key(key);
+        buckets.push(bucket);
+      }
+      Ok(buckets)
+    }
+  }
+
+  deserializer.deserialize_any(BucketsVisitor(PhantomData))
+}
+
+/// Response of a `filter` aggregation, a single bucket of the documents
+/// matching its filter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterAggResult {
+  pub doc_count: u64,
+  #[serde(flatten, default)]
+  pub aggregations: SubAggregations,
+}
+
+/// Response of a `nested` or `reverse_nested` aggregation, a single bucket of
+/// the nested, or parent, documents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NestedAggResult {
+  pub doc_count: u64,
+  #[serde(flatten, default)]
   pub aggregations: SubAggregations,
 }
 
+/// Bucket of a `geohash_grid`, `geotile_grid` or `geohex_grid` aggregation.
+///
+/// `key` is the token of the grid cell, e.g. a geohash such as `u173z` or a
+/// `{zoom}/{x}/{y}` tile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeoGridBucket {
+  pub key: String,
+  pub doc_count: u64,
+  #[serde(flatten, default)]
+  pub aggregations: SubAggregations,
+}
+
+/// Response of a geo grid aggregation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeoGridAggregation {
+  #[serde(default)]
+  pub buckets: Vec<GeoGridBucket>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MatrixRow {
   pub key: serde_json::Value,
   #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
   pub aggregations: SubAggregations,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompositeBucket {
+  pub key: serde_json::Map<String, serde_json::Value>,
+  pub doc_count: u64,
+  #[serde(flatten, default)]
+  pub aggregations: SubAggregations,
+}
+
+/// Response of a `composite` aggregation.
+///
+/// `after_key` holds the key of the last returned bucket and is absent once
+/// every bucket has been returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompositeAggregation {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub after_key: Option<serde_json::Map<String, serde_json::Value>>,
+  #[serde(default)]
+  pub buckets: Vec<CompositeBucket>,
+}
+
+impl CompositeAggregation {
+  /// Returns the key to send as `composite.after` to fetch the next page of
+  /// buckets, or `None` once the last page has been reached.
+  pub fn next_after(&self) -> Option<serde_json::Value> {
+    self.after_key.clone().map(serde_json::Value::Object)
+  }
+}
+
+/// Shortcuts for reading the sub-aggregations of a bucket.
+pub trait BucketExt {
+  /// Sub-aggregations of the bucket
+  fn aggregations(&self) -> &SubAggregations;
+
+  /// Value of the single-value metric sub-aggregation `name`, such as an
+  /// `avg` or `sum`, or `None` if it is missing or has no value
+  fn sub_metric(&self, name: &str) -> Option<f64> {
+    self.aggregations().get::<SingleMetric>(name)?.value
+  }
+}
+
+macro_rules! impl_bucket_ext {
+  ($($bucket:ty),*) => {
+    $(
+      impl BucketExt for $bucket {
+        fn aggregations(&self) -> &SubAggregations {
+          &self.aggregations
+        }
+      }
+    )*
+  };
+}
+
+impl_bucket_ext!(
+  TermsBucket,
+  RangeBucket,
+  DateRangeBucket,
+  HistogramBucket,
+  DateHistogramBucket,
+  GeoDistanceBucket,
+  FiltersBucket,
+  FilterAggResult,
+  NestedAggResult,
+  GeoGridBucket,
+  CompositeBucket
+);
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use serde::de::DeserializeOwned;
+  use serde_json::json;
+
+  use super::*;
+  use crate::types::Aggregations;
+
+  fn load_entity<T: DeserializeOwned>(name: &str) -> T {
+    let filename = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("tests/types/{name}"));
+    let text = std::fs::read_to_string(filename).unwrap();
+    serde_json::from_str(&text).unwrap()
+  }
+
+  #[test]
+  fn test_decode_composite() {
+    let decoded: CompositeAggregation = load_entity("composite.response.json");
+    assert_eq!(
+      decoded.after_key,
+      json!({"date": 1494374400000u64, "product": "mad max"})
+        .as_object()
+        .cloned()
+    );
+    assert_eq!(decoded.buckets.len(), 3);
+    assert_eq!(decoded.buckets[0].key["product"], json!("rocky"));
+    assert_eq!(decoded.buckets[0].key["date"], json!(1494288000000u64));
+    assert_eq!(decoded.buckets[0].doc_count, 1);
+    assert!(decoded.buckets[1].aggregations.contains_key("price"));
+    assert_eq!(decoded.buckets[2].doc_count, 2);
+  }
+
+  #[test]
+  fn test_decode_composite_last_page() {
+    let decoded: CompositeAggregation = serde_json::from_value(json!({ "buckets": [] })).unwrap();
+    assert_eq!(decoded.after_key, None);
+    assert!(decoded.buckets.is_empty());
+  }
+
+  #[test]
+  fn test_composite_next_after_pages() {
+    let mut request = json!({
+      "composite": {
+        "size": 3,
+        "sources": [{ "product": { "terms": { "field": "product" } } }]
+      }
+    });
+
+    let first_page: CompositeAggregation = load_entity("composite.response.json");
+    let after = first_page.next_after().expect("first page should have an after_key");
+    request["composite"]["after"] = after;
+    assert_eq!(
+      request["composite"]["after"],
+      json!({"date": 1494374400000u64, "product": "mad max"})
+    );
+
+    let second_page: CompositeAggregation = serde_json::from_value(json!({
+      "buckets": [{ "key": { "date": 1494460800000u64, "product": "the godfather" }, "doc_count": 1 }]
+    }))
+    .unwrap();
+    assert_eq!(second_page.buckets.len(), 1);
+    assert_eq!(second_page.next_after(), None);
+  }
+
+  #[test]
+  fn test_decode_geo_grid_buckets() {
+    let decoded: GeoGridAggregation = serde_json::from_value(json!({
+      "buckets": [
+        { "key": "u173z", "doc_count": 3, "max_rating": { "value": 4.5 } },
+        { "key": "u17", "doc_count": 1 }
+      ]
+    }))
+    .unwrap();
+    assert_eq!(decoded.buckets.len(), 2);
+    assert_eq!(decoded.buckets[0].key, "u173z");
+    assert_eq!(decoded.buckets[0].doc_count, 3);
+    assert!(decoded.buckets[0].aggregations.contains_key("max_rating"));
+    assert_eq!(decoded.buckets[1].key, "u17");
+    assert!(decoded.buckets[1].aggregations.is_empty());
+  }
+
+  #[test]
+  fn test_decode_terms() {
+    let decoded: TermsAggResult = serde_json::from_value(json!({
+      "doc_count_error_upper_bound": 46,
+      "sum_other_doc_count": 79,
+      "buckets": [
+        { "key": "Product A", "doc_count": 100 },
+        { "key": 42, "key_as_string": "42", "doc_count": 52 }
+      ]
+    }))
+    .unwrap();
+    assert_eq!(decoded.doc_count_error_upper_bound, 46);
+    assert_eq!(decoded.sum_other_doc_count, 79);
+    assert_eq!(decoded.buckets.len(), 2);
+    assert_eq!(decoded.buckets[1].key, json!(42));
+    assert_eq!(decoded.buckets[1].key_as_string.as_deref(), Some("42"));
+    assert!(decoded.buckets[1].aggregations.is_empty());
+  }
+
+  #[test]
+  fn test_decode_range() {
+    let decoded: RangeAggResult = serde_json::from_value(json!({
+      "buckets": [
+        { "key": "*-100.0", "to": 100.0, "doc_count": 2 },
+        { "key": "100.0-200.0", "from": 100.0, "to": 200.0, "doc_count": 2 },
+        { "key": "200.0-*", "from": 200.0, "doc_count": 3 }
+      ]
+    }))
+    .unwrap();
+    assert_eq!(decoded.buckets.len(), 3);
+    assert_eq!(decoded.buckets[0].key.as_deref(), Some("*-100.0"));
+    assert_eq!(decoded.buckets[0].from, None);
+    assert_eq!(decoded.buckets[1].to, Some(200.0));
+    assert_eq!(decoded.buckets[2].doc_count, 3);
+  }
+
+  #[test]
+  fn test_decode_keyed_range() {
+    let decoded: RangeAggResult = serde_json::from_value(json!({
+      "buckets": {
+        "cheap": { "to": 100.0, "doc_count": 2 },
+        "average": { "from": 100.0, "to": 200.0, "doc_count": 2, "avg_price": { "value": 150.0 } },
+        "expensive": { "from": 200.0, "doc_count": 3 }
+      }
+    }))
+    .unwrap();
+    assert_eq!(
+      decoded.buckets.iter().map(|b| b.key.as_deref()).collect::<Vec<_>>(),
+      [Some("average"), Some("cheap"), Some("expensive")]
+    );
+    assert_eq!(decoded.buckets[0].from, Some(100.0));
+    assert_eq!(decoded.buckets[0].sub_metric("avg_price"), Some(150.0));
+    assert_eq!(decoded.buckets[1].to, Some(100.0));
+  }
+
+  #[test]
+  fn test_decode_keyed_range_in_response_order() {
+    let decoded: RangeAggResult = serde_json::from_str(
+      r#"{"buckets": {"cheap": {"to": 100.0, "doc_count": 2}, "average": {"from": 100.0, "doc_count": 1}}}"#,
+    )
+    .unwrap();
+    assert_eq!(decoded.buckets[0].key.as_deref(), Some("cheap"));
+    assert_eq!(decoded.buckets[1].key.as_deref(), Some("average"));
+  }
+
+  #[test]
+  fn test_decode_filters() {
+    let named: FiltersAggResult = serde_json::from_value(json!({
+      "buckets": { "errors": { "doc_count": 1 }, "warnings": { "doc_count": 2 } }
+    }))
+    .unwrap();
+    assert_eq!(named.buckets[1].key.as_deref(), Some("warnings"));
+    assert_eq!(named.buckets[1].doc_count, 2);
+
+    let anonymous: FiltersAggResult = serde_json::from_value(json!({
+      "buckets": [{ "doc_count": 1 }, { "doc_count": 2 }]
+    }))
+    .unwrap();
+    assert_eq!(anonymous.buckets[0].key, None);
+    assert_eq!(anonymous.buckets[1].doc_count, 2);
+  }
+
+  #[test]
+  fn test_decode_date_range() {
+    let decoded: DateRangeAggResult = serde_json::from_value(json!({
+      "buckets": {
+        "older": { "to": 1.4436576E12, "to_as_string": "10-2015", "doc_count": 7 }
+      }
+    }))
+    .unwrap();
+    assert_eq!(decoded.buckets[0].key.as_deref(), Some("older"));
+    assert_eq!(decoded.buckets[0].to, Some(1.4436576E12));
+    assert_eq!(decoded.buckets[0].to_as_string.as_deref(), Some("10-2015"));
+  }
+
+  #[test]
+  fn test_bucket_sub_metric() {
+    let decoded: TermsAggResult = serde_json::from_value(json!({
+      "buckets": [
+        { "key": "rock", "doc_count": 3, "avg_price": { "value": 12.5 } },
+        { "key": "jazz", "doc_count": 1, "avg_price": { "value": null } },
+        { "key": "pop", "doc_count": 1 }
+      ]
+    }))
+    .unwrap();
+
+    let prices: Vec<Option<f64>> = decoded.buckets.iter().map(|b| b.sub_metric("avg_price")).collect();
+    assert_eq!(prices, [Some(12.5), None, None]);
+  }
+
+  #[test]
+  fn test_decode_composite_aggregations() {
+    let decoded: Aggregations = load_entity("composite.response.json");
+    match decoded {
+      Aggregations::Composite { after_key, buckets, .. } => {
+        assert_eq!(after_key.unwrap()["product"], json!("mad max"));
+        assert_eq!(buckets.len(), 3);
+      }
+      other => panic!("expected a composite aggregation, got {other:?}"),
+    }
+  }
+
+  #[test]
+  fn test_decode_composite_aggregations_last_page() {
+    let decoded: Aggregations = serde_json::from_value(json!({
+      "buckets": [
+        { "key": { "date": 1494374400000u64, "product": "rocky" }, "doc_count": 1 }
+      ]
+    }))
+    .unwrap();
+    match decoded {
+      Aggregations::Composite { after_key, buckets, .. } => {
+        assert_eq!(after_key, None);
+        assert_eq!(buckets[0].key["product"], json!("rocky"));
+      }
+      other => panic!("expected a composite aggregation, got {other:?}"),
+    }
+  }
+
+  #[test]
+  fn test_decode_filter_aggregations_keeps_children() {
+    let decoded: Aggregations =
+      serde_json::from_value(json!({ "doc_count": 4, "avg_rating": { "value": 7.5 } })).unwrap();
+    match decoded {
+      Aggregations::Filter {
+        doc_count,
+        aggregations,
+      } => {
+        assert_eq!(doc_count, 4);
+        assert_eq!(aggregations.avg("avg_rating").unwrap().value, Some(7.5));
+      }
+      other => panic!("expected a filter aggregation, got {other:?}"),
+    }
+  }
+}
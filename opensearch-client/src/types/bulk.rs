@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::ShardStatistics;
+use super::ShardStats;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct UpdateActionBody {
@@ -152,6 +152,18 @@ impl UpdateActionBody {
   }
 }
 
+impl From<Value> for UpdateActionBody {
+  fn from(doc: Value) -> Self {
+    Self::new(doc)
+  }
+}
+
+impl From<Script> for UpdateActionBody {
+  fn from(script: Script) -> Self {
+    Self::with_script(script)
+  }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndexResponse {
   #[serde(rename = "_index")]
@@ -163,7 +175,7 @@ pub struct IndexResponse {
   #[serde(rename = "result")]
   pub result: String,
   #[serde(rename = "_shards", default, skip_serializing_if = "Option::is_none")]
-  pub shards: Option<ShardStatistics>,
+  pub shards: Option<ShardStats>,
   #[serde(rename = "_seq_no", default)]
   pub seq_no: i64,
   #[serde(rename = "_primary_term", default)]
@@ -221,6 +233,190 @@ pub struct DeleteAction {
   pub id: String,
 }
 
+/// Batch of bulk operations, serialized to the newline-delimited JSON body of
+/// a `_bulk` request.
+///
+/// `id`, `target_index` and `routing` apply to the most recently added
+/// operation.
+///
+/// ```
+/// use opensearch_client::types::bulk::BulkRequest;
+/// use serde_json::json;
+///
+/// let request = BulkRequest::new()
+///   .index(json!({ "title": "Moneyball" }))
+///   .target_index("movies")
+///   .id("1")
+///   .delete("2")
+///   .target_index("movies");
+///
+/// assert_eq!(
+///   request.to_ndjson()?,
+///   "{\"index\":{\"_index\":\"movies\",\"_id\":\"1\"}}\n{\"title\":\"Moneyball\"}\n{\"delete\":{\"_index\":\"movies\",\"_id\":\"2\"}}\n"
+/// );
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BulkRequest {
+  operations: Vec<BulkOperation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct BulkOperation {
+  action: BulkActionKind,
+  metadata: BulkMetadata,
+  source: Option<BulkSource>,
+}
+
+/// Metadata of the action line of a bulk operation
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+struct BulkMetadata {
+  #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+  index: Option<String>,
+  #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+  id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  routing: Option<String>,
+}
+
+/// Source line of a bulk operation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum BulkSource {
+  Document(Value),
+  Update(UpdateActionBody),
+}
+
+impl BulkRequest {
+  /// Creates an empty bulk request
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Indexes `doc`, replacing any document with the same id
+  pub fn index(self, doc: Value) -> Self {
+    self.push(BulkActionKind::Index, None, Some(BulkSource::Document(doc)))
+  }
+
+  /// Indexes `doc`, failing if a document with the same id already exists
+  pub fn create(self, doc: Value) -> Self {
+    self.push(BulkActionKind::Create, None, Some(BulkSource::Document(doc)))
+  }
+
+  /// Updates the document `id` with `body`, a partial document to merge or a
+  /// script to run
+  pub fn update<T: ToString>(self, id: T, body: impl Into<UpdateActionBody>) -> Self {
+    self.push(
+      BulkActionKind::Update,
+      Some(id.to_string()),
+      Some(BulkSource::Update(body.into())),
+    )
+  }
+
+  /// Deletes the document `id`
+  pub fn delete<T: ToString>(self, id: T) -> Self {
+    self.push(BulkActionKind::Delete, Some(id.to_string()), None)
+  }
+
+  /// Sets the `_id` of the last operation
+  ///
+  /// # Panics
+  ///
+  /// Panics if the request has no operations, or if the last operation
+  /// already has an id, as `update` and `delete` operations do.
+  pub fn id<T: ToString>(mut self, id: T) -> Self {
+    let metadata = self.last_metadata("id");
+    assert!(
+      metadata.id.is_none(),
+      "the last operation of the bulk request already has an id"
+    );
+    metadata.id = Some(id.to_string());
+    self
+  }
+
+  /// Sets the `_index` of the last operation, which otherwise targets the
+  /// index of the request path
+  ///
+  /// # Panics
+  ///
+  /// Panics if the request has no operations.
+  pub fn target_index<T: ToString>(mut self, index: T) -> Self {
+    self.last_metadata("target_index").index = Some(index.to_string());
+    self
+  }
+
+  /// Sets the `routing` of the last operation
+  ///
+  /// # Panics
+  ///
+  /// Panics if the request has no operations.
+  pub fn routing<T: ToString>(mut self, routing: T) -> Self {
+    self.last_metadata("routing").routing = Some(routing.to_string());
+    self
+  }
+
+  /// Number of operations in the request
+  pub fn len(&self) -> usize {
+    self.operations.len()
+  }
+
+  /// Whether the request has no operations
+  pub fn is_empty(&self) -> bool {
+    self.operations.is_empty()
+  }
+
+  /// Serializes the request to newline-delimited JSON, an action line per
+  /// operation followed by its source line, if any, each ending with a newline
+  pub fn to_ndjson(&self) -> Result<String, serde_json::Error> {
+    let mut ndjson = String::new();
+    for operation in &self.operations {
+      let action = HashMap::from([(operation.action, &operation.metadata)]);
+      ndjson.push_str(&serde_json::to_string(&action)?);
+      ndjson.push('\n');
+      if let Some(source) = &operation.source {
+        ndjson.push_str(&serde_json::to_string(source)?);
+        ndjson.push('\n');
+      }
+    }
+    Ok(ndjson)
+  }
+
+  fn push(mut self, action: BulkActionKind, id: Option<String>, source: Option<BulkSource>) -> Self {
+    self.operations.push(BulkOperation {
+      action,
+      metadata: BulkMetadata {
+        id,
+        ..Default::default()
+      },
+      source,
+    });
+    self
+  }
+
+  fn last_metadata(&mut self, setter: &str) -> &mut BulkMetadata {
+    match self.operations.last_mut() {
+      Some(operation) => &mut operation.metadata,
+      None => panic!("`{setter}` called on a bulk request without operations"),
+    }
+  }
+}
+
+impl TryFrom<&BulkRequest> for String {
+  type Error = serde_json::Error;
+
+  fn try_from(request: &BulkRequest) -> Result<Self, serde_json::Error> {
+    request.to_ndjson()
+  }
+}
+
+impl TryFrom<BulkRequest> for String {
+  type Error = serde_json::Error;
+
+  fn try_from(request: BulkRequest) -> Result<Self, serde_json::Error> {
+    request.to_ndjson()
+  }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BulkResponse {
   pub took: u64,
@@ -252,11 +448,51 @@ impl BulkResponse {
       .filter(|i| i.values().any(|i| i.status == 409))
       .count()
   }
+
+  /// Items of the operations that failed, with the kind of their operation,
+  /// in the order of the request
+  ///
+  /// Only items with an `error` are returned, so a `delete` of a missing
+  /// document, answered with a `404` and a `not_found` result, isn't a
+  /// failure.
+  pub fn failed_items(&self) -> impl Iterator<Item = (BulkActionKind, &BulkItemResponse)> {
+    self
+      .items
+      .iter()
+      .flat_map(|i| i.iter())
+      .filter(|(_, item)| item.error.is_some())
+      .filter_map(|(kind, item)| Some((kind.parse().ok()?, item)))
+  }
+}
+
+/// Kind of operation of a bulk item, as named in the request and the
+/// response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkActionKind {
+  Index,
+  Create,
+  Update,
+  Delete,
+}
+
+impl std::str::FromStr for BulkActionKind {
+  type Err = &'static str;
+
+  fn from_str(value: &str) -> Result<Self, &'static str> {
+    match value {
+      "index" => Ok(Self::Index),
+      "create" => Ok(Self::Create),
+      "update" => Ok(Self::Update),
+      "delete" => Ok(Self::Delete),
+      _ => Err("invalid value"),
+    }
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct BulkError {
-  #[serde(rename = "_index")]
+  #[serde(rename = "_index", alias = "index")]
   pub index: Option<String>,
   #[serde(default)]
   pub index_uuid: Option<String>,
@@ -281,7 +517,7 @@ pub struct BulkItemResponse {
   #[serde(default)]
   pub found: Option<bool>,
   #[serde(rename = "_shards", default, skip_serializing_if = "Option::is_none")]
-  pub shards: Option<ShardStatistics>,
+  pub shards: Option<ShardStats>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub error: Option<BulkError>,
   #[serde(default, rename = "_primary_term", skip_serializing_if = "Option::is_none")]
@@ -297,3 +533,135 @@ pub struct Script {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub params: Option<serde_json::Value>,
 }
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_bulk_request_ndjson() {
+    let request = BulkRequest::new()
+      .index(json!({ "title": "Moneyball" }))
+      .target_index("movies")
+      .id("1")
+      .create(json!({ "title": "Prisoners" }))
+      .target_index("movies")
+      .routing("user1")
+      .update("1", json!({ "year": 2011 }))
+      .delete("2")
+      .target_index("books");
+
+    assert_eq!(request.len(), 4);
+    assert_eq!(
+      request.to_ndjson().unwrap(),
+      concat!(
+        "{\"index\":{\"_index\":\"movies\",\"_id\":\"1\"}}\n",
+        "{\"title\":\"Moneyball\"}\n",
+        "{\"create\":{\"_index\":\"movies\",\"routing\":\"user1\"}}\n",
+        "{\"title\":\"Prisoners\"}\n",
+        "{\"update\":{\"_id\":\"1\"}}\n",
+        "{\"doc\":{\"year\":2011}}\n",
+        "{\"delete\":{\"_index\":\"books\",\"_id\":\"2\"}}\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_bulk_request_update_body() {
+    let request = BulkRequest::new()
+      .update(
+        "1",
+        UpdateActionBody::from_script_parameters("ctx._source.views += params.n", json!({ "n": 1 })),
+      )
+      .routing("user1")
+      .update(
+        "2",
+        UpdateActionBody {
+          doc: Some(json!({ "year": 2011 })),
+          doc_as_upsert: Some(true),
+          script: None,
+        },
+      );
+
+    assert_eq!(
+      request.to_ndjson().unwrap(),
+      concat!(
+        "{\"update\":{\"_id\":\"1\",\"routing\":\"user1\"}}\n",
+        "{\"script\":{\"source\":\"ctx._source.views += params.n\",\"params\":{\"n\":1}}}\n",
+        "{\"update\":{\"_id\":\"2\"}}\n",
+        "{\"doc\":{\"year\":2011},\"doc_as_upsert\":true}\n",
+      )
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "already has an id")]
+  fn test_bulk_request_id_keeps_update_id() {
+    let _ = BulkRequest::new().update("1", json!({ "year": 2011 })).id("2");
+  }
+
+  #[test]
+  #[should_panic(expected = "`target_index` called on a bulk request without operations")]
+  fn test_bulk_request_metadata_without_operations() {
+    let _ = BulkRequest::new().target_index("movies");
+  }
+
+  #[test]
+  fn test_bulk_response_failed_items() {
+    let response: BulkResponse = serde_json::from_value(json!({
+      "took": 30,
+      "errors": true,
+      "items": [
+        {
+          "index": {
+            "_index": "movies",
+            "_id": "1",
+            "_version": 1,
+            "result": "created",
+            "status": 201,
+            "_seq_no": 0,
+            "_primary_term": 1
+          }
+        },
+        {
+          "create": {
+            "_index": "movies",
+            "_id": "2",
+            "status": 409,
+            "error": {
+              "type": "version_conflict_engine_exception",
+              "reason": "[2]: version conflict, document already exists (current version [1])",
+              "index": "movies",
+              "shard": "0",
+              "index_uuid": "yhizhusbSWmP0G7OJnmcLg"
+            }
+          }
+        },
+        {
+          "delete": { "_index": "movies", "_id": "3", "_version": 1, "result": "not_found", "status": 404 }
+        }
+      ]
+    }))
+    .unwrap();
+
+    assert!(!response.is_ok());
+    let failed: Vec<(BulkActionKind, &BulkItemResponse)> = response.failed_items().collect();
+    assert_eq!(failed.len(), 1);
+    let (kind, item) = failed[0];
+    assert_eq!(kind, BulkActionKind::Create);
+    assert_eq!(item.id, "2");
+    let error = item.error.as_ref().unwrap();
+    assert_eq!(error.kind, "version_conflict_engine_exception");
+    assert_eq!(error.index.as_deref(), Some("movies"));
+  }
+
+  #[test]
+  fn test_empty_bulk_request() {
+    let request = BulkRequest::new();
+
+    assert!(request.is_empty());
+    assert_eq!(String::try_from(&request).unwrap(), "");
+  }
+}
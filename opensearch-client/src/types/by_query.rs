@@ -0,0 +1,205 @@
+use opensearch_dsl::{Query, Script};
+use serde::Serialize;
+
+use super::{reindex::ReindexResponse, Conflicts, DeleteByQueryBodyParams, UpdateByQueryBodyParams};
+
+/// Body of an `_update_by_query` request, updating the documents matching a
+/// query in place.
+///
+/// ```
+/// use opensearch_client::{
+///   dsl::{Query, Script},
+///   types::by_query::UpdateByQuery,
+/// };
+///
+/// let request = UpdateByQuery::new()
+///   .query(Query::term("genre", "drama"))
+///   .script(Script::source("ctx._source.views++"));
+///
+/// assert_eq!(
+///   serde_json::to_string(&request).unwrap(),
+///   "{\"query\":{\"term\":{\"genre\":{\"value\":\"drama\"}}},\"script\":{\"source\":\"ctx._source.views++\"}}"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct UpdateByQuery {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  conflicts: Option<Conflicts>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_docs: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  query: Option<Query>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  script: Option<Script>,
+}
+
+impl UpdateByQuery {
+  /// Creates an update by query matching every document
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only updates the documents matching the query
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> Self {
+    self.query = Some(query.into());
+    self
+  }
+
+  /// Script updating each document, without which documents are reindexed
+  /// as they are
+  pub fn script(mut self, script: Script) -> Self {
+    self.script = Some(script);
+    self
+  }
+
+  /// Whether to abort or proceed on version conflicts
+  pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+    self.conflicts = Some(conflicts);
+    self
+  }
+
+  /// Maximum number of documents to update
+  pub fn max_docs(mut self, max_docs: u64) -> Self {
+    self.max_docs = Some(max_docs);
+    self
+  }
+}
+
+/// Body of a `_delete_by_query` request, deleting the documents matching a
+/// query.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteByQuery {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  conflicts: Option<Conflicts>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_docs: Option<u64>,
+  query: Query,
+}
+
+impl DeleteByQuery {
+  /// Creates a delete by query deleting the documents matching the query
+  pub fn new<Q: Into<Query>>(query: Q) -> Self {
+    Self {
+      conflicts: None,
+      max_docs: None,
+      query: query.into(),
+    }
+  }
+
+  /// Whether to abort or proceed on version conflicts
+  pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+    self.conflicts = Some(conflicts);
+    self
+  }
+
+  /// Maximum number of documents to delete
+  pub fn max_docs(mut self, max_docs: u64) -> Self {
+    self.max_docs = Some(max_docs);
+    self
+  }
+}
+
+impl From<&UpdateByQuery> for UpdateByQueryBodyParams {
+  fn from(value: &UpdateByQuery) -> Self {
+    match serde_json::to_value(value) {
+      Ok(serde_json::Value::Object(body)) => Self(body),
+      _ => Self(serde_json::Map::new()),
+    }
+  }
+}
+
+impl From<UpdateByQuery> for UpdateByQueryBodyParams {
+  fn from(value: UpdateByQuery) -> Self {
+    Self::from(&value)
+  }
+}
+
+impl From<&DeleteByQuery> for DeleteByQueryBodyParams {
+  fn from(value: &DeleteByQuery) -> Self {
+    match serde_json::to_value(value) {
+      Ok(serde_json::Value::Object(body)) => Self(body),
+      _ => Self(serde_json::Map::new()),
+    }
+  }
+}
+
+impl From<DeleteByQuery> for DeleteByQueryBodyParams {
+  fn from(value: DeleteByQuery) -> Self {
+    Self::from(&value)
+  }
+}
+
+/// Response of an `_update_by_query` or `_delete_by_query` request, which
+/// has the shape of a reindex response with `created` always `0`.
+pub type ByQueryResponse = ReindexResponse;
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_update_by_query_body() {
+    let request = UpdateByQuery::new()
+      .query(Query::range("year").lt(2000))
+      .script(Script::source("ctx._source.classic = params.classic").param("classic", true))
+      .conflicts(Conflicts::Proceed)
+      .max_docs(100);
+
+    assert_eq!(
+      serde_json::to_value(&request).unwrap(),
+      json!({
+        "conflicts": "proceed",
+        "max_docs": 100,
+        "query": { "range": { "year": { "lt": 2000 } } },
+        "script": {
+          "source": "ctx._source.classic = params.classic",
+          "params": { "classic": true }
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn test_delete_by_query_body() {
+    let request = DeleteByQuery::new(Query::term("status", "archived")).conflicts(Conflicts::Abort);
+
+    assert_eq!(
+      serde_json::Value::Object(DeleteByQueryBodyParams::from(&request).0),
+      json!({
+        "conflicts": "abort",
+        "query": { "term": { "status": { "value": "archived" } } }
+      })
+    );
+  }
+
+  #[test]
+  fn test_decode_response() {
+    let decoded: ByQueryResponse = serde_json::from_value(json!({
+      "took": 12,
+      "timed_out": false,
+      "total": 5,
+      "deleted": 4,
+      "batches": 1,
+      "version_conflicts": 1,
+      "noops": 0,
+      "retries": { "bulk": 0, "search": 0 },
+      "throttled_millis": 0,
+      "requests_per_second": -1.0,
+      "throttled_until_millis": 0,
+      "failures": [{
+        "index": "movies",
+        "id": "7",
+        "cause": { "type": "version_conflict_engine_exception", "reason": "[7]: version conflict" },
+        "status": 409
+      }]
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.deleted, 4);
+    assert_eq!(decoded.updated, 0);
+    assert_eq!(decoded.version_conflicts, 1);
+    assert_eq!(decoded.failures[0].status, Some(409));
+  }
+}
@@ -0,0 +1,87 @@
+use opensearch_dsl::{Query, Search};
+use serde::{Deserialize, Serialize};
+
+/// Body of a `_count` request, counting the documents matching a query
+/// without fetching any hit.
+///
+/// Converts into the [`Search`] body taken by
+/// [`OsClient::count`](crate::OsClient::count).
+///
+/// ```
+/// use opensearch_client::{dsl::Query, types::count::CountRequest};
+///
+/// let request = CountRequest::new().query(Query::term("genre", "drama"));
+///
+/// assert_eq!(
+///   serde_json::to_string(&request).unwrap(),
+///   "{\"query\":{\"term\":{\"genre\":{\"value\":\"drama\"}}}}"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CountRequest {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub query: Option<Query>,
+}
+
+impl CountRequest {
+  /// Creates a count request matching every document
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only counts the documents matching the query
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> Self {
+    self.query = Some(query.into());
+    self
+  }
+}
+
+impl From<CountRequest> for Search {
+  fn from(value: CountRequest) -> Self {
+    match value.query {
+      Some(query) => Search::new().query(query),
+      None => Search::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::types::CountResponse;
+
+  #[test]
+  fn test_count_request_body() {
+    let request = CountRequest::new().query(
+      Query::bool()
+        .must(Query::term("genre", "drama"))
+        .filter(Query::range("year").gte(2000)),
+    );
+
+    let expected = json!({
+      "query": {
+        "bool": {
+          "must": [{ "term": { "genre": { "value": "drama" } } }],
+          "filter": [{ "range": { "year": { "gte": 2000 } } }]
+        }
+      }
+    });
+    assert_eq!(serde_json::to_value(&request).unwrap(), expected);
+    let search: Search = request.into();
+    assert_eq!(serde_json::to_value(search).unwrap(), expected);
+  }
+
+  #[test]
+  fn test_decode_count_response() {
+    let decoded: CountResponse = serde_json::from_value(json!({
+      "count": 4_294_967_296u64,
+      "_shards": { "total": 5, "successful": 5, "skipped": 0, "failed": 0 }
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.count, 4_294_967_296);
+    assert_eq!(decoded._shards.unwrap().successful, 5);
+  }
+}
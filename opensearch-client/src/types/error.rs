@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// Error envelope returned by OpenSearch for a failed request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+  pub error: ErrorCause,
+  pub status: u16,
+}
+
+/// Cause of a failed request, such as an `index_not_found_exception`.
+///
+/// `root_cause` lists the innermost causes reported by each shard, while
+/// `caused_by` holds the exception that led to this one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ErrorCause {
+  #[serde(rename = "type")]
+  pub kind: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub root_cause: Vec<ErrorCause>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub caused_by: Option<Box<ErrorCause>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub index: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub index_uuid: Option<String>,
+}
+
+impl std::fmt::Display for ErrorCause {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.reason {
+      Some(reason) => write!(f, "{}: {}", self.kind, reason),
+      None => f.write_str(&self.kind),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_decode_index_not_found() {
+    let decoded: ErrorResponse = serde_json::from_value(json!({
+      "error": {
+        "root_cause": [{
+          "type": "index_not_found_exception",
+          "reason": "no such index [movies]",
+          "index": "movies",
+          "resource.id": "movies",
+          "resource.type": "index_or_alias",
+          "index_uuid": "_na_"
+        }],
+        "type": "index_not_found_exception",
+        "reason": "no such index [movies]",
+        "index": "movies",
+        "resource.id": "movies",
+        "resource.type": "index_or_alias",
+        "index_uuid": "_na_"
+      },
+      "status": 404
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.status, 404);
+    assert_eq!(decoded.error.kind, "index_not_found_exception");
+    assert_eq!(decoded.error.index.as_deref(), Some("movies"));
+    assert_eq!(decoded.error.root_cause.len(), 1);
+    assert_eq!(decoded.error.root_cause[0].index_uuid.as_deref(), Some("_na_"));
+    assert_eq!(
+      decoded.error.to_string(),
+      "index_not_found_exception: no such index [movies]"
+    );
+  }
+
+  #[test]
+  fn test_decode_nested_cause() {
+    let decoded: ErrorResponse = serde_json::from_value(json!({
+      "error": {
+        "root_cause": [{ "type": "parsing_exception", "reason": "unknown query [mtch]" }],
+        "type": "x_content_parse_exception",
+        "reason": "[1:20] [bool] failed to parse field [must]",
+        "caused_by": { "type": "parsing_exception", "reason": "unknown query [mtch]" }
+      },
+      "status": 400
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.error.kind, "x_content_parse_exception");
+    assert_eq!(decoded.error.root_cause[0].kind, "parsing_exception");
+    assert_eq!(
+      decoded.error.caused_by.unwrap().reason.as_deref(),
+      Some("unknown query [mtch]")
+    );
+  }
+}
@@ -3,10 +3,26 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 
 use serde::{Deserialize, Serialize};
+pub mod aggregations;
 pub mod bulk;
 pub mod buckets;
-
-pub use bulk::{BulkAction, BulkError, BulkItemResponse, BulkResponse, IndexResponse, UpdateAction};
+#[cfg(feature = "search")]
+pub mod by_query;
+#[cfg(feature = "search")]
+pub mod count;
+pub mod error;
+#[cfg(feature = "search")]
+pub mod msearch;
+#[cfg(feature = "search")]
+pub mod reindex;
+pub mod shards;
+
+pub use aggregations::AggregationResults;
+pub use bulk::{BulkAction, BulkActionKind, BulkError, BulkItemResponse, BulkResponse, IndexResponse, UpdateAction};
+pub use error::{ErrorCause, ErrorResponse};
+#[cfg(feature = "search")]
+pub use opensearch_dsl::{Explanation, Profile};
+pub use shards::{ShardFailure, ShardStats};
 
 ///The unit in which to display byte values.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -265,9 +281,9 @@ impl std::convert::TryFrom<String> for Conflicts {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CountResponse {
   #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub _shards: Option<ShardStatistics>,
+  pub _shards: Option<ShardStats>,
   #[serde(default)]
-  pub count: u32,
+  pub count: u64,
 }
 
 ///Query to restrict the results specified with the Query DSL (optional)
@@ -631,7 +647,7 @@ pub struct CreatePitResponseContent {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub pit_id: Option<String>,
   #[serde(rename = "_shard", default, skip_serializing_if = "Option::is_none")]
-  pub shard: Option<ShardStatistics>,
+  pub shard: Option<ShardStats>,
 }
 
 impl From<&CreatePitResponseContent> for CreatePitResponseContent {
@@ -1338,7 +1354,12 @@ pub struct Hit<T> {
   #[serde(rename = "_type", default, skip_serializing_if = "Option::is_none")]
   pub type_: Option<String>,
   #[serde(rename = "sort", default, skip_serializing_if = "Option::is_none")]
-  pub sort: Option<serde_json::Value>,
+  pub sort: Option<Vec<serde_json::Value>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub matched_queries: Option<MatchedQueries>,
+  #[cfg(feature = "search")]
+  #[serde(rename = "_explanation", default, skip_serializing_if = "Option::is_none")]
+  pub explanation: Option<Explanation>,
 }
 
 impl<T> From<&Hit<T>> for Hit<T> {
@@ -1353,6 +1374,43 @@ impl<T> Hit<T> {
   }
 }
 
+/// Names of the named queries that matched a hit.
+///
+/// Returned as a list of names, or as a map of name to score when the search
+/// was run with `include_named_queries_score`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum MatchedQueries {
+  Names(Vec<String>),
+  Scores(HashMap<String, f64>),
+}
+
+impl MatchedQueries {
+  /// Names of the matched queries.
+  pub fn names(&self) -> Vec<&str> {
+    match self {
+      Self::Names(names) => names.iter().map(String::as_str).collect(),
+      Self::Scores(scores) => scores.keys().map(String::as_str).collect(),
+    }
+  }
+
+  /// Whether the query with the given name matched.
+  pub fn contains(&self, name: &str) -> bool {
+    match self {
+      Self::Names(names) => names.iter().any(|n| n == name),
+      Self::Scores(scores) => scores.contains_key(name),
+    }
+  }
+
+  /// Score of the named query, only available in the scored form.
+  pub fn score(&self, name: &str) -> Option<f64> {
+    match self {
+      Self::Names(_) => None,
+      Self::Scores(scores) => scores.get(name).copied(),
+    }
+  }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HitsMetadata<T> {
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -2552,7 +2610,7 @@ pub struct SearchGetResponseContent<T> {
   #[serde(rename = "_scroll_id", default, skip_serializing_if = "Option::is_none")]
   pub scroll_id: Option<String>,
   #[serde(rename = "_shards", default, skip_serializing_if = "Option::is_none")]
-  pub shards: Option<ShardStatistics>,
+  pub shards: Option<ShardStats>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub timed_out: Option<bool>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2718,9 +2776,24 @@ impl<'de> serde::Deserialize<'de> for SearchGetWithIndexScroll {
 }
 
 /// Type representing sub-aggregations in nested aggregations
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+///
+/// Results are kept as returned by OpenSearch, like [`AggregationResults`],
+/// and parsed on access. Entries that aren't objects, such as the
+/// `key_as_string` of the bucket the sub-aggregations are flattened into, are
+/// skipped.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 #[serde(rename = "aggregations")]
-pub struct SubAggregations(pub HashMap<String, Aggregations>);
+pub struct SubAggregations(pub HashMap<String, serde_json::Value>);
+
+impl<'de> Deserialize<'de> for SubAggregations {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>, {
+    let mut entries = HashMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    entries.retain(|_, value| value.is_object());
+    Ok(SubAggregations(entries))
+  }
+}
 
 impl SubAggregations {
   /// Check if there are no sub-aggregations
@@ -2735,7 +2808,7 @@ impl SubAggregations {
 }
 
 impl std::ops::Deref for SubAggregations {
-  type Target = HashMap<String, Aggregations>;
+  type Target = HashMap<String, serde_json::Value>;
 
   fn deref(&self) -> &Self::Target {
     &self.0
@@ -2748,8 +2821,8 @@ impl std::ops::DerefMut for SubAggregations {
   }
 }
 
-impl From<HashMap<String, Aggregations>> for SubAggregations {
-  fn from(map: HashMap<String, Aggregations>) -> Self {
+impl From<HashMap<String, serde_json::Value>> for SubAggregations {
+  fn from(map: HashMap<String, serde_json::Value>) -> Self {
     SubAggregations(map)
   }
 }
@@ -2821,6 +2894,13 @@ pub enum Aggregations {
     #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
     aggregations: SubAggregations,
   },
+  Composite {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after_key: Option<serde_json::Map<String, serde_json::Value>>,
+    buckets: Vec<buckets::CompositeBucket>,
+    #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+    aggregations: SubAggregations,
+  },
   Range {
     buckets: Vec<RangeBucket>,
     #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
@@ -2848,7 +2928,7 @@ pub enum Aggregations {
   },
   Filter {
     doc_count: u64,
-    #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+    #[serde(flatten, default)]
     aggregations: SubAggregations,
   },
   Filters {
@@ -2898,7 +2978,7 @@ pub enum Aggregations {
   // Nested aggregations
   Nested {
     doc_count: u64,
-    #[serde(default, skip_serializing_if = "SubAggregations::is_empty")]
+    #[serde(flatten, default)]
     aggregations: SubAggregations,
   },
 
@@ -2999,11 +3079,14 @@ pub struct SearchPostResponseContent<T> {
   #[serde(rename = "_scroll_id", default, skip_serializing_if = "Option::is_none")]
   pub scroll_id: Option<String>,
   #[serde(rename = "_shards", default, skip_serializing_if = "Option::is_none")]
-  pub shards: Option<ShardStatistics>,
+  pub shards: Option<ShardStats>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub timed_out: Option<bool>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub took: Option<i64>,
+  #[cfg(feature = "search")]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub profile: Option<Profile>,
 }
 
 impl<T> From<&SearchPostResponseContent<T>> for SearchPostResponseContent<T> {
@@ -3016,6 +3099,12 @@ impl<T> SearchPostResponseContent<T> {
   pub fn builder() -> builder::SearchPostResponseContent<T> {
     builder::SearchPostResponseContent::default()
   }
+
+  /// Aggregation results of the response, with typed accessors such as
+  /// [`terms`](AggregationResults::terms) or [`avg`](AggregationResults::avg)
+  pub fn aggregation_results(&self) -> Option<AggregationResults> {
+    self.aggregations.as_ref().map(AggregationResults::from)
+  }
 }
 
 ///Specify how long a consistent view of the index should be maintained for
@@ -3098,13 +3187,16 @@ pub struct SearchResult<T> {
   #[serde(rename = "_scroll_id", default, skip_serializing_if = "Option::is_none")]
   pub scroll_id: Option<String>,
   #[serde(rename = "_shards", default, skip_serializing_if = "Option::is_none")]
-  pub shards: Option<ShardStatistics>,
+  pub shards: Option<ShardStats>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub timed_out: Option<bool>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub took: Option<i64>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub aggregations: Option<HashMap<String, Aggregations>>,
+  #[cfg(feature = "search")]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub profile: Option<Profile>,
 }
 
 impl<T> From<&SearchResult<T>> for SearchResult<T> {
@@ -3117,8 +3209,20 @@ impl<T> SearchResult<T> {
   pub fn builder() -> builder::SearchResult<T> {
     builder::SearchResult::default()
   }
+
+  /// Aggregation results of the response, with typed accessors such as
+  /// [`terms`](AggregationResults::terms) or [`avg`](AggregationResults::avg)
+  pub fn aggregation_results(&self) -> Option<AggregationResults> {
+    self.aggregations.as_ref().map(AggregationResults::from)
+  }
 }
 
+/// Response body of a search request, generic over the document `_source`.
+pub type SearchResponse<T> = SearchResult<T>;
+
+/// The `hits` section of a [`SearchResponse`].
+pub type Hits<T> = HitsMetadata<T>;
+
 ///Specify how long a consistent view of the index should be maintained for
 /// scrolled search.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -3641,30 +3745,6 @@ impl std::convert::TryFrom<String> for SearchTypeMulti {
   }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
-pub struct ShardStatistics {
-  #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub failed: Option<i32>,
-  #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub skipped: Option<i32>,
-  #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub successful: Option<i32>,
-  #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub total: Option<i32>,
-}
-
-impl From<&ShardStatistics> for ShardStatistics {
-  fn from(value: &ShardStatistics) -> Self {
-    value.clone()
-  }
-}
-
-impl ShardStatistics {
-  pub fn builder() -> builder::ShardStatistics {
-    builder::ShardStatistics::default()
-  }
-}
-
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum StatusMember {
   #[serde(rename = "green")]
@@ -3906,12 +3986,41 @@ impl std::convert::TryFrom<String> for Time {
   }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Total {
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub relation: Option<Relation>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
-  pub value: Option<i32>,
+  pub value: Option<u64>,
+}
+
+impl<'de> serde::Deserialize<'de> for Total {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>, {
+    // `rest_total_hits_as_int` and older clusters report a bare integer
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+      Count(u64),
+      Object {
+        #[serde(default)]
+        relation: Option<Relation>,
+        #[serde(default)]
+        value: Option<u64>,
+      },
+    }
+
+    Ok(match Repr::deserialize(deserializer)? {
+      Repr::Count(value) => {
+        Self {
+          relation: Some(Relation::Eq),
+          value: Some(value),
+        }
+      }
+      Repr::Object { relation, value } => Self { relation, value },
+    })
+  }
 }
 
 impl From<&Total> for Total {
@@ -4463,7 +4572,7 @@ pub mod builder {
   pub struct CreatePitResponseContent {
     creation_time: Result<Option<i64>, String>,
     pit_id: Result<Option<String>, String>,
-    shard: Result<Option<super::ShardStatistics>, String>,
+    shard: Result<Option<super::ShardStats>, String>,
   }
 
   impl Default for CreatePitResponseContent {
@@ -4499,7 +4608,7 @@ pub mod builder {
 
     pub fn shard<T>(mut self, value: T) -> Self
     where
-      T: std::convert::TryInto<Option<super::ShardStatistics>>,
+      T: std::convert::TryInto<Option<super::ShardStats>>,
       T::Error: std::fmt::Display, {
       self.shard = value
         .try_into()
@@ -5131,7 +5240,10 @@ pub mod builder {
     score: Result<Option<f64>, String>,
     source: Result<Option<T>, String>,
     type_: Result<Option<String>, String>,
-    sort: Result<Option<serde_json::Value>, String>,
+    sort: Result<Option<Vec<serde_json::Value>>, String>,
+    matched_queries: Result<Option<super::MatchedQueries>, String>,
+    #[cfg(feature = "search")]
+    explanation: Result<Option<super::Explanation>, String>,
   }
 
   impl<T> Default for Hit<T> {
@@ -5144,6 +5256,9 @@ pub mod builder {
         source: Ok(Default::default()),
         type_: Ok(Default::default()),
         sort: Ok(Default::default()),
+        matched_queries: Ok(Default::default()),
+        #[cfg(feature = "search")]
+        explanation: Ok(Default::default()),
       }
     }
   }
@@ -5208,6 +5323,27 @@ pub mod builder {
         .map_err(|e| format!("error converting supplied value for type_: {}", e));
       self
     }
+
+    pub fn matched_queries<T>(mut self, value: T) -> Self
+    where
+      T: std::convert::TryInto<Option<super::MatchedQueries>>,
+      T::Error: std::fmt::Display, {
+      self.matched_queries = value
+        .try_into()
+        .map_err(|e| format!("error converting supplied value for matched_queries: {}", e));
+      self
+    }
+
+    #[cfg(feature = "search")]
+    pub fn explanation<T>(mut self, value: T) -> Self
+    where
+      T: std::convert::TryInto<Option<super::Explanation>>,
+      T::Error: std::fmt::Display, {
+      self.explanation = value
+        .try_into()
+        .map_err(|e| format!("error converting supplied value for explanation: {}", e));
+      self
+    }
   }
 
   impl<T2> std::convert::TryFrom<Hit<T2>> for super::Hit<T2> {
@@ -5222,6 +5358,9 @@ pub mod builder {
         source: value.source?,
         type_: value.type_?,
         sort: value.sort?,
+        matched_queries: value.matched_queries?,
+        #[cfg(feature = "search")]
+        explanation: value.explanation?,
       })
     }
   }
@@ -5236,6 +5375,9 @@ pub mod builder {
         source: Ok(value.source),
         type_: Ok(value.type_),
         sort: Ok(value.sort),
+        matched_queries: Ok(value.matched_queries),
+        #[cfg(feature = "search")]
+        explanation: Ok(value.explanation),
       }
     }
   }
@@ -5936,7 +6078,7 @@ pub mod builder {
   pub struct SearchGetResponseContent<T> {
     hits: Result<Option<super::HitsMetadata<T>>, String>,
     scroll_id: Result<Option<String>, String>,
-    shards: Result<Option<super::ShardStatistics>, String>,
+    shards: Result<Option<super::ShardStats>, String>,
     timed_out: Result<Option<bool>, String>,
     took: Result<Option<i64>, String>,
   }
@@ -5976,7 +6118,7 @@ pub mod builder {
 
     pub fn shards<T>(mut self, value: T) -> Self
     where
-      T: std::convert::TryInto<Option<super::ShardStatistics>>,
+      T: std::convert::TryInto<Option<super::ShardStats>>,
       T::Error: std::fmt::Display, {
       self.shards = value
         .try_into()
@@ -6035,10 +6177,12 @@ pub mod builder {
   pub struct SearchPostResponseContent<T> {
     hits: Result<Option<super::HitsMetadata<T>>, String>,
     scroll_id: Result<Option<String>, String>,
-    shards: Result<Option<super::ShardStatistics>, String>,
+    shards: Result<Option<super::ShardStats>, String>,
     timed_out: Result<Option<bool>, String>,
     took: Result<Option<i64>, String>,
     aggregations: Result<Option<HashMap<String, Aggregations>>, String>,
+    #[cfg(feature = "search")]
+    profile: Result<Option<super::Profile>, String>,
   }
 
   impl<T2> Default for SearchPostResponseContent<T2> {
@@ -6050,6 +6194,8 @@ pub mod builder {
         timed_out: Ok(Default::default()),
         took: Ok(Default::default()),
         aggregations: Ok(Default::default()),
+        #[cfg(feature = "search")]
+        profile: Ok(Default::default()),
       }
     }
   }
@@ -6077,7 +6223,7 @@ pub mod builder {
 
     pub fn shards<T>(mut self, value: T) -> Self
     where
-      T: std::convert::TryInto<Option<super::ShardStatistics>>,
+      T: std::convert::TryInto<Option<super::ShardStats>>,
       T::Error: std::fmt::Display, {
       self.shards = value
         .try_into()
@@ -6104,6 +6250,17 @@ pub mod builder {
         .map_err(|e| format!("error converting supplied value for took: {}", e));
       self
     }
+
+    #[cfg(feature = "search")]
+    pub fn profile<T>(mut self, value: T) -> Self
+    where
+      T: std::convert::TryInto<Option<super::Profile>>,
+      T::Error: std::fmt::Display, {
+      self.profile = value
+        .try_into()
+        .map_err(|e| format!("error converting supplied value for profile: {}", e));
+      self
+    }
   }
 
   impl<T2> std::convert::TryFrom<SearchPostResponseContent<T2>> for super::SearchPostResponseContent<T2> {
@@ -6117,6 +6274,8 @@ pub mod builder {
         timed_out: value.timed_out?,
         took: value.took?,
         aggregations: value.aggregations?,
+        #[cfg(feature = "search")]
+        profile: value.profile?,
       })
     }
   }
@@ -6130,6 +6289,8 @@ pub mod builder {
         timed_out: Ok(value.timed_out),
         took: Ok(value.took),
         aggregations: Ok(value.aggregations),
+        #[cfg(feature = "search")]
+        profile: Ok(value.profile),
       }
     }
   }
@@ -6138,10 +6299,12 @@ pub mod builder {
   pub struct SearchResult<T> {
     hits: Result<super::HitsMetadata<T>, String>,
     scroll_id: Result<Option<String>, String>,
-    shards: Result<Option<super::ShardStatistics>, String>,
+    shards: Result<Option<super::ShardStats>, String>,
     timed_out: Result<Option<bool>, String>,
     took: Result<Option<i64>, String>,
     aggregations: Result<Option<HashMap<String, Aggregations>>, String>,
+    #[cfg(feature = "search")]
+    profile: Result<Option<super::Profile>, String>,
   }
 
   impl<T> Default for SearchResult<T> {
@@ -6153,6 +6316,8 @@ pub mod builder {
         timed_out: Ok(Default::default()),
         took: Ok(Default::default()),
         aggregations: Ok(Default::default()),
+        #[cfg(feature = "search")]
+        profile: Ok(Default::default()),
       }
     }
   }
@@ -6180,7 +6345,7 @@ pub mod builder {
 
     pub fn shards<T>(mut self, value: T) -> Self
     where
-      T: std::convert::TryInto<Option<super::ShardStatistics>>,
+      T: std::convert::TryInto<Option<super::ShardStats>>,
       T::Error: std::fmt::Display, {
       self.shards = value
         .try_into()
@@ -6207,6 +6372,17 @@ pub mod builder {
         .map_err(|e| format!("error converting supplied value for took: {}", e));
       self
     }
+
+    #[cfg(feature = "search")]
+    pub fn profile<T>(mut self, value: T) -> Self
+    where
+      T: std::convert::TryInto<Option<super::Profile>>,
+      T::Error: std::fmt::Display, {
+      self.profile = value
+        .try_into()
+        .map_err(|e| format!("error converting supplied value for profile: {}", e));
+      self
+    }
   }
 
   impl<T> std::convert::TryFrom<SearchResult<T>> for super::SearchResult<T> {
@@ -6220,6 +6396,8 @@ pub mod builder {
         timed_out: value.timed_out?,
         took: value.took?,
         aggregations: value.aggregations?,
+        #[cfg(feature = "search")]
+        profile: value.profile?,
       })
     }
   }
@@ -6233,91 +6411,8 @@ pub mod builder {
         timed_out: Ok(value.timed_out),
         took: Ok(value.took),
         aggregations: Ok(value.aggregations),
-      }
-    }
-  }
-
-  #[derive(Clone, Debug)]
-  pub struct ShardStatistics {
-    failed: Result<Option<i32>, String>,
-    skipped: Result<Option<i32>, String>,
-    successful: Result<Option<i32>, String>,
-    total: Result<Option<i32>, String>,
-  }
-
-  impl Default for ShardStatistics {
-    fn default() -> Self {
-      Self {
-        failed: Ok(Default::default()),
-        skipped: Ok(Default::default()),
-        successful: Ok(Default::default()),
-        total: Ok(Default::default()),
-      }
-    }
-  }
-
-  impl ShardStatistics {
-    pub fn failed<T>(mut self, value: T) -> Self
-    where
-      T: std::convert::TryInto<Option<i32>>,
-      T::Error: std::fmt::Display, {
-      self.failed = value
-        .try_into()
-        .map_err(|e| format!("error converting supplied value for failed: {}", e));
-      self
-    }
-
-    pub fn skipped<T>(mut self, value: T) -> Self
-    where
-      T: std::convert::TryInto<Option<i32>>,
-      T::Error: std::fmt::Display, {
-      self.skipped = value
-        .try_into()
-        .map_err(|e| format!("error converting supplied value for skipped: {}", e));
-      self
-    }
-
-    pub fn successful<T>(mut self, value: T) -> Self
-    where
-      T: std::convert::TryInto<Option<i32>>,
-      T::Error: std::fmt::Display, {
-      self.successful = value
-        .try_into()
-        .map_err(|e| format!("error converting supplied value for successful: {}", e));
-      self
-    }
-
-    pub fn total<T>(mut self, value: T) -> Self
-    where
-      T: std::convert::TryInto<Option<i32>>,
-      T::Error: std::fmt::Display, {
-      self.total = value
-        .try_into()
-        .map_err(|e| format!("error converting supplied value for total: {}", e));
-      self
-    }
-  }
-
-  impl std::convert::TryFrom<ShardStatistics> for super::ShardStatistics {
-    type Error = String;
-
-    fn try_from(value: ShardStatistics) -> Result<Self, String> {
-      Ok(Self {
-        failed: value.failed?,
-        skipped: value.skipped?,
-        successful: value.successful?,
-        total: value.total?,
-      })
-    }
-  }
-
-  impl From<super::ShardStatistics> for ShardStatistics {
-    fn from(value: super::ShardStatistics) -> Self {
-      Self {
-        failed: Ok(value.failed),
-        skipped: Ok(value.skipped),
-        successful: Ok(value.successful),
-        total: Ok(value.total),
+        #[cfg(feature = "search")]
+        profile: Ok(value.profile),
       }
     }
   }
@@ -6325,7 +6420,7 @@ pub mod builder {
   #[derive(Clone, Debug)]
   pub struct Total {
     relation: Result<Option<super::Relation>, String>,
-    value: Result<Option<i32>, String>,
+    value: Result<Option<u64>, String>,
   }
 
   impl Default for Total {
@@ -6350,7 +6445,7 @@ pub mod builder {
 
     pub fn value<T>(mut self, value: T) -> Self
     where
-      T: std::convert::TryInto<Option<i32>>,
+      T: std::convert::TryInto<Option<u64>>,
       T::Error: std::fmt::Display, {
       self.value = value
         .try_into()
@@ -7344,9 +7439,215 @@ pub struct DocumentDeleteResponse {
   pub version: u32,
   pub result: String,
   #[serde(rename = "_shards")]
-  pub shards: ShardStatistics,
+  pub shards: ShardStats,
   #[serde(rename = "_seq_no")]
   pub seq_no: u32,
   #[serde(rename = "_primary_term")]
   pub primary_term: u32,
 }
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use serde::de::DeserializeOwned;
+  use serde_json::json;
+
+  use super::*;
+
+  fn load_entity<T: DeserializeOwned>(name: &str) -> T {
+    let filename = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("tests/types/{name}"));
+    let text = std::fs::read_to_string(filename).unwrap();
+    serde_json::from_str(&text).unwrap()
+  }
+
+  #[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+  struct Movie {
+    title: String,
+    year: u32,
+  }
+
+  #[test]
+  fn test_decode_search_response() {
+    let decoded: SearchResponse<Movie> = load_entity("search.response.json");
+    assert_eq!(decoded.took, Some(5));
+    assert_eq!(decoded.timed_out, Some(false));
+    assert_eq!(decoded.shards.unwrap().successful, 1);
+
+    let hits: Hits<Movie> = decoded.hits;
+    assert_eq!(hits.total.unwrap().value, Some(2));
+    assert_eq!(hits.max_score, Some(1.3862942));
+    assert_eq!(hits.hits.len(), 2);
+
+    let first = &hits.hits[0];
+    assert_eq!(first.index, "movies");
+    assert_eq!(first.id, "1");
+    assert_eq!(first.score, Some(1.3862942));
+    assert_eq!(
+      first.source,
+      Some(Movie {
+        title: "The Godfather".to_string(),
+        year: 1972
+      })
+    );
+    assert_eq!(first.sort, Some(vec![json!(1.3862942), json!("1")]));
+
+    let second = &hits.hits[1];
+    assert_eq!(second.score, None);
+    assert_eq!(second.sort, None);
+    assert_eq!(second.source.as_ref().map(|movie| movie.year), Some(1979));
+
+    let aggregations = decoded.aggregations.unwrap();
+    assert!(matches!(aggregations["avg_year"], Aggregations::Avg { value, .. } if value == 1975.5));
+  }
+
+  #[test]
+  fn test_decode_search_response_terms_aggregation() {
+    let decoded: SearchResult<Movie> = serde_json::from_value(json!({
+      "took": 3,
+      "timed_out": false,
+      "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+      "hits": { "total": { "value": 3, "relation": "eq" }, "max_score": null, "hits": [] },
+      "aggregations": {
+        "genres": {
+          "doc_count_error_upper_bound": 0,
+          "sum_other_doc_count": 1,
+          "buckets": [
+            { "key": "drama", "doc_count": 2, "avg_year": { "value": 1975.5 } },
+            { "key": "crime", "doc_count": 1, "avg_year": { "value": 1972.0 } }
+          ]
+        }
+      }
+    }))
+    .unwrap();
+
+    use crate::types::buckets::BucketExt;
+
+    let genres = decoded.aggregation_results().unwrap().terms("genres").unwrap();
+    assert_eq!(genres.sum_other_doc_count, 1);
+    assert_eq!(genres.buckets[0].key, json!("drama"));
+    assert_eq!(genres.buckets[0].doc_count, 2);
+    assert_eq!(genres.buckets[1].sub_metric("avg_year"), Some(1972.0));
+  }
+
+  #[test]
+  fn test_decode_total_hits_object() {
+    let total: Total = serde_json::from_value(json!({ "value": 10000, "relation": "gte" })).unwrap();
+    assert_eq!(total.value, Some(10000));
+    assert_eq!(total.relation, Some(Relation::Gte));
+  }
+
+  #[test]
+  fn test_decode_total_hits_integer() {
+    let hits: Hits<Movie> = serde_json::from_value(json!({ "total": 4_294_967_296u64, "hits": [] })).unwrap();
+    let total = hits.total.unwrap();
+    assert_eq!(total.value, Some(4_294_967_296));
+    assert_eq!(total.relation, Some(Relation::Eq));
+  }
+
+  #[test]
+  fn test_decode_matched_queries_names() {
+    let hit: Hit<Movie> = serde_json::from_value(json!({
+      "_index": "movies",
+      "_id": "1",
+      "matched_queries": ["title_match", "recent"]
+    }))
+    .unwrap();
+    let matched = hit.matched_queries.unwrap();
+    assert_eq!(matched.names(), vec!["title_match", "recent"]);
+    assert!(matched.contains("recent"));
+    assert_eq!(matched.score("recent"), None);
+  }
+
+  #[test]
+  fn test_decode_matched_queries_scores() {
+    let hit: Hit<Movie> = serde_json::from_value(json!({
+      "_index": "movies",
+      "_id": "1",
+      "matched_queries": { "title_match": 1.2, "recent": 1.0 }
+    }))
+    .unwrap();
+    let matched = hit.matched_queries.unwrap();
+    assert!(matched.contains("title_match"));
+    assert!(!matched.contains("genre"));
+    assert_eq!(matched.score("title_match"), Some(1.2));
+  }
+
+  #[cfg(feature = "search")]
+  #[test]
+  fn test_decode_explanation_tree() {
+    let hit: Hit<Movie> = serde_json::from_value(json!({
+      "_index": "movies",
+      "_id": "1",
+      "_explanation": {
+        "value": 1.6943598,
+        "description": "weight(title:godfather in 0) [PerFieldSimilarity], result of:",
+        "details": [
+          {
+            "value": 1.6943598,
+            "description": "score(freq=1.0), computed as boost * idf * tf from:",
+            "details": [
+              { "value": 2.2, "description": "boost", "details": [] },
+              { "value": 1.3862942, "description": "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5)) from:" }
+            ]
+          }
+        ]
+      }
+    }))
+    .unwrap();
+
+    let explanation = hit.explanation.unwrap();
+    assert_eq!(explanation.value, 1.6943598);
+    assert_eq!(explanation.details.len(), 1);
+
+    let score = &explanation.details[0];
+    assert!(score.description.starts_with("score(freq=1.0)"));
+    assert_eq!(score.details.len(), 2);
+    assert_eq!(score.details[0].description, "boost");
+    assert!(score.details[1].details.is_empty());
+  }
+
+  #[cfg(feature = "search")]
+  #[test]
+  fn test_decode_search_profile() {
+    let decoded: SearchResult<Movie> = serde_json::from_value(json!({
+      "took": 2,
+      "hits": { "total": { "value": 0, "relation": "eq" }, "hits": [] },
+      "profile": {
+        "shards": [
+          {
+            "id": "[d2WxnN2QTsOh8fSRauG8ZQ][movies][0]",
+            "searches": [
+              {
+                "query": [
+                  {
+                    "type": "TermQuery",
+                    "description": "title:godfather",
+                    "time_in_nanos": 14536,
+                    "breakdown": { "score": 3087, "score_count": 3 }
+                  }
+                ],
+                "rewrite_time": 8922,
+                "collector": [
+                  { "name": "SimpleTopScoreDocCollector", "reason": "search_top_hits", "time_in_nanos": 25678 }
+                ]
+              }
+            ]
+          }
+        ]
+      }
+    }))
+    .unwrap();
+
+    let profile = decoded.profile.unwrap();
+    assert_eq!(profile.shards.len(), 1);
+    let search = &profile.shards[0].searches[0];
+    assert_eq!(search.query[0].r#type, "TermQuery");
+    assert_eq!(search.query[0].breakdown["score_count"], 3);
+    assert_eq!(search.collector[0].reason, "search_top_hits");
+
+    let content: SearchPostResponseContent<Movie> =
+      serde_json::from_value(json!({ "profile": { "shards": [] } })).unwrap();
+    assert_eq!(content.profile, Some(Profile::default()));
+  }
+}
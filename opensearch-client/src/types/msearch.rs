@@ -0,0 +1,243 @@
+use opensearch_dsl::Search;
+use serde::{
+  de::{DeserializeOwned, Error as _},
+  Deserialize, Deserializer, Serialize,
+};
+use serde_json::Value;
+
+use super::{ErrorResponse, SearchResult, SearchType};
+
+/// Searches to run in a single `_msearch` request, serialized to its
+/// newline-delimited JSON body.
+///
+/// ```
+/// use opensearch_client::{
+///   dsl::{Query, Search},
+///   types::msearch::{MultiSearch, MultiSearchHeader},
+/// };
+///
+/// let request = MultiSearch::new()
+///   .search(
+///     MultiSearchHeader::new().index("movies"),
+///     Search::new().size(1),
+///   )
+///   .search(
+///     MultiSearchHeader::new().index("books"),
+///     Search::new().query(Query::match_all()),
+///   );
+///
+/// assert_eq!(
+///   request.to_ndjson()?,
+///   "{\"index\":\"movies\"}\n{\"size\":1}\n{\"index\":\"books\"}\n{\"query\":{\"match_all\":{}}}\n"
+/// );
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MultiSearch {
+  searches: Vec<(MultiSearchHeader, Search)>,
+}
+
+impl MultiSearch {
+  /// Creates an empty multi search
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a search with its header
+  pub fn search(mut self, header: MultiSearchHeader, body: Search) -> Self {
+    self.searches.push((header, body));
+    self
+  }
+
+  /// Number of searches
+  pub fn len(&self) -> usize {
+    self.searches.len()
+  }
+
+  /// Whether there are no searches
+  pub fn is_empty(&self) -> bool {
+    self.searches.is_empty()
+  }
+
+  /// Serializes the searches to newline-delimited JSON, a header line followed
+  /// by a body line per search, each ending with a newline
+  pub fn to_ndjson(&self) -> Result<String, serde_json::Error> {
+    let mut ndjson = String::new();
+    for (header, body) in &self.searches {
+      for line in [serde_json::to_value(header)?, serde_json::to_value(body)?] {
+        ndjson.push_str(&line.to_string());
+        ndjson.push('\n');
+      }
+    }
+    Ok(ndjson)
+  }
+}
+
+/// Header line of a search in a [`MultiSearch`].
+///
+/// Searches without an `index` run against the index of the request path.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MultiSearchHeader {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  index: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  search_type: Option<SearchType>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  preference: Option<String>,
+}
+
+impl MultiSearchHeader {
+  /// Creates an empty header
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Index, or comma-separated indices, to search
+  pub fn index<T: ToString>(mut self, index: T) -> Self {
+    self.index = Some(index.to_string());
+    self
+  }
+
+  /// How distributed term frequencies are computed for scoring
+  pub fn search_type(mut self, search_type: SearchType) -> Self {
+    self.search_type = Some(search_type);
+    self
+  }
+
+  /// Nodes or shards to run the search on, such as `_local`
+  pub fn preference<T: ToString>(mut self, preference: T) -> Self {
+    self.preference = Some(preference.to_string());
+    self
+  }
+}
+
+/// Response of an `_msearch` request.
+///
+/// `responses` hold the result of each search in the order of the request,
+/// or the error it failed with. Entries with an `error` key are decoded as
+/// [`ErrorResponse`], all others as [`SearchResult`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound(deserialize = "T: DeserializeOwned + Default"))]
+pub struct MultiSearchResponse<T> {
+  #[serde(default)]
+  pub took: Option<u64>,
+  #[serde(deserialize_with = "deserialize_responses")]
+  pub responses: Vec<Result<SearchResult<T>, ErrorResponse>>,
+}
+
+fn deserialize_responses<'de, D, T>(deserializer: D) -> Result<Vec<Result<SearchResult<T>, ErrorResponse>>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: DeserializeOwned + Default, {
+  Vec::<Value>::deserialize(deserializer)?
+    .into_iter()
+    .enumerate()
+    .map(|(position, response)| {
+      decode_response(response).map_err(|e| D::Error::custom(format!("invalid response {position}: {e}")))
+    })
+    .collect()
+}
+
+fn decode_response<T: DeserializeOwned + Default>(
+  response: Value,
+) -> Result<Result<SearchResult<T>, ErrorResponse>, serde_json::Error> {
+  if response.get("error").is_some() {
+    serde_json::from_value(response).map(Err)
+  } else {
+    serde_json::from_value(response).map(Ok)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use opensearch_dsl::Query;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_multi_search_ndjson() {
+    let request = MultiSearch::new()
+      .search(
+        MultiSearchHeader::new()
+          .index("movies")
+          .search_type(SearchType::DfsQueryThenFetch)
+          .preference("_local"),
+        Search::new().query(Query::term("genre", "drama")),
+      )
+      .search(MultiSearchHeader::new(), Search::new().size(0));
+
+    assert_eq!(request.len(), 2);
+    assert_eq!(
+      request.to_ndjson().unwrap(),
+      concat!(
+        "{\"index\":\"movies\",\"preference\":\"_local\",\"search_type\":\"dfs_query_then_fetch\"}\n",
+        "{\"query\":{\"term\":{\"genre\":{\"value\":\"drama\"}}}}\n",
+        "{}\n",
+        "{\"size\":0}\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_decode_mixed_responses() {
+    #[derive(Debug, Default, PartialEq, Deserialize)]
+    struct Movie {
+      title: String,
+    }
+
+    let decoded: MultiSearchResponse<Movie> = serde_json::from_value(json!({
+      "took": 12,
+      "responses": [
+        {
+          "took": 5,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 1, "relation": "eq" },
+            "max_score": 1.0,
+            "hits": [{ "_index": "movies", "_id": "1", "_score": 1.0, "_source": { "title": "Moneyball" } }]
+          },
+          "status": 200
+        },
+        {
+          "error": {
+            "root_cause": [{ "type": "index_not_found_exception", "reason": "no such index [books]" }],
+            "type": "index_not_found_exception",
+            "reason": "no such index [books]"
+          },
+          "status": 404
+        }
+      ]
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.took, Some(12));
+    assert_eq!(decoded.responses.len(), 2);
+
+    let hits = &decoded.responses[0].as_ref().unwrap().hits.hits;
+    assert_eq!(
+      hits[0].source,
+      Some(Movie {
+        title: "Moneyball".to_string(),
+      })
+    );
+
+    let error = decoded.responses[1].as_ref().unwrap_err();
+    assert_eq!(error.status, 404);
+    assert_eq!(error.error.kind, "index_not_found_exception");
+  }
+  #[test]
+  fn test_decode_reports_malformed_result() {
+    let error = serde_json::from_value::<MultiSearchResponse<serde_json::Value>>(json!({
+      "responses": [
+        { "hits": { "hits": [] }, "status": 200 },
+        { "hits": { "hits": "none" }, "status": 200 }
+      ]
+    }))
+    .unwrap_err();
+
+    let message = error.to_string();
+    assert!(message.starts_with("invalid response 1: invalid type"), "{message}");
+  }
+}
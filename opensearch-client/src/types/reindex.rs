@@ -0,0 +1,264 @@
+use opensearch_dsl::{Query, Script, SourceFilter};
+use serde::{Deserialize, Serialize};
+
+use super::{Conflicts, ErrorCause, OpType, ReindexBodyParams};
+
+/// Body of a `_reindex` request, copying documents from a source index to a
+/// destination index.
+///
+/// ```
+/// use opensearch_client::{
+///   dsl::{Query, Script},
+///   types::{reindex::Reindex, Conflicts},
+/// };
+///
+/// let request = Reindex::new("movies", "movies-v2")
+///   .query(Query::term("genre", "drama"))
+///   .conflicts(Conflicts::Proceed)
+///   .script(Script::source("ctx._source.remove('rating')"));
+///
+/// assert_eq!(
+///   serde_json::to_string(&request).unwrap(),
+///   "{\"conflicts\":\"proceed\",\"source\":{\"index\":\"movies\",\"query\":{\"term\":{\"genre\":{\"value\":\"drama\"}}}},\"dest\":{\"index\":\"movies-v2\"},\"script\":{\"source\":\"ctx._source.remove('rating')\"}}"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Reindex {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  conflicts: Option<Conflicts>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_docs: Option<u64>,
+  source: ReindexSource,
+  dest: ReindexDest,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  script: Option<Script>,
+}
+
+/// Documents to copy in a [`Reindex`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReindexSource {
+  index: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  query: Option<Query>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  size: Option<u64>,
+  #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+  source_filter: Option<SourceFilter>,
+}
+
+/// Where the documents of a [`Reindex`] are copied to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReindexDest {
+  index: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  op_type: Option<OpType>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pipeline: Option<String>,
+}
+
+impl Reindex {
+  /// Creates a reindex copying every document of the `source` index, or
+  /// comma-separated indices, to the `dest` index
+  pub fn new<S: ToString, D: ToString>(source: S, dest: D) -> Self {
+    Self {
+      conflicts: None,
+      max_docs: None,
+      source: ReindexSource {
+        index: source.to_string(),
+        query: None,
+        size: None,
+        source_filter: None,
+      },
+      dest: ReindexDest {
+        index: dest.to_string(),
+        op_type: None,
+        pipeline: None,
+      },
+      script: None,
+    }
+  }
+
+  /// Only copies the source documents matching the query
+  pub fn query<Q: Into<Query>>(mut self, query: Q) -> Self {
+    self.source.query = Some(query.into());
+    self
+  }
+
+  /// Number of documents to read from the source per batch
+  pub fn size(mut self, size: u64) -> Self {
+    self.source.size = Some(size);
+    self
+  }
+
+  /// Only copies the parts of the source documents matching the filter
+  pub fn source_filter<F: Into<SourceFilter>>(mut self, source_filter: F) -> Self {
+    self.source.source_filter = Some(source_filter.into());
+    self
+  }
+
+  /// Whether to only create documents missing from the destination, or to
+  /// also overwrite existing ones
+  pub fn op_type(mut self, op_type: OpType) -> Self {
+    self.dest.op_type = Some(op_type);
+    self
+  }
+
+  /// Ingest pipeline the documents go through before being indexed
+  pub fn pipeline<T: ToString>(mut self, pipeline: T) -> Self {
+    self.dest.pipeline = Some(pipeline.to_string());
+    self
+  }
+
+  /// Whether to abort or proceed on version conflicts
+  pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+    self.conflicts = Some(conflicts);
+    self
+  }
+
+  /// Maximum number of documents to copy
+  pub fn max_docs(mut self, max_docs: u64) -> Self {
+    self.max_docs = Some(max_docs);
+    self
+  }
+
+  /// Script transforming the documents while they are copied
+  pub fn script(mut self, script: Script) -> Self {
+    self.script = Some(script);
+    self
+  }
+}
+
+impl From<&Reindex> for ReindexBodyParams {
+  fn from(value: &Reindex) -> Self {
+    match serde_json::to_value(value) {
+      Ok(serde_json::Value::Object(body)) => Self(body),
+      _ => Self(serde_json::Map::new()),
+    }
+  }
+}
+
+impl From<Reindex> for ReindexBodyParams {
+  fn from(value: Reindex) -> Self {
+    Self::from(&value)
+  }
+}
+
+/// Response of a `_reindex` request, also returned by `_update_by_query` and
+/// `_delete_by_query` as [`ByQueryResponse`](super::by_query::ByQueryResponse).
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ReindexResponse {
+  #[serde(default)]
+  pub took: u64,
+  #[serde(default)]
+  pub timed_out: bool,
+  #[serde(default)]
+  pub total: u64,
+  #[serde(default)]
+  pub created: u64,
+  #[serde(default)]
+  pub updated: u64,
+  #[serde(default)]
+  pub deleted: u64,
+  #[serde(default)]
+  pub batches: u64,
+  #[serde(default)]
+  pub version_conflicts: u64,
+  #[serde(default)]
+  pub noops: u64,
+  #[serde(default)]
+  pub failures: Vec<ReindexFailure>,
+}
+
+/// Document that failed to be copied, or shard that failed to be read, by a
+/// reindex.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ReindexFailure {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub index: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub status: Option<u16>,
+  #[serde(default, alias = "reason", skip_serializing_if = "Option::is_none")]
+  pub cause: Option<ErrorCause>,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_reindex_body() {
+    let request = Reindex::new("movies", "movies-v2")
+      .query(Query::range("year").gte(2000))
+      .size(500)
+      .source_filter(["title", "year"])
+      .op_type(OpType::Create)
+      .pipeline("enrich")
+      .conflicts(Conflicts::Proceed)
+      .max_docs(10_000);
+
+    assert_eq!(
+      serde_json::to_value(&request).unwrap(),
+      json!({
+        "conflicts": "proceed",
+        "max_docs": 10000,
+        "source": {
+          "index": "movies",
+          "query": { "range": { "year": { "gte": 2000 } } },
+          "size": 500,
+          "_source": ["title", "year"]
+        },
+        "dest": { "index": "movies-v2", "op_type": "create", "pipeline": "enrich" }
+      })
+    );
+    assert_eq!(
+      serde_json::Value::Object(ReindexBodyParams::from(&request).0),
+      serde_json::to_value(&request).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_decode_response_with_failures() {
+    let decoded: ReindexResponse = serde_json::from_value(json!({
+      "took": 147,
+      "timed_out": false,
+      "total": 3,
+      "updated": 0,
+      "created": 2,
+      "deleted": 0,
+      "batches": 1,
+      "version_conflicts": 1,
+      "noops": 0,
+      "retries": { "bulk": 0, "search": 0 },
+      "throttled_millis": 0,
+      "requests_per_second": -1.0,
+      "throttled_until_millis": 0,
+      "failures": [{
+        "index": "movies-v2",
+        "id": "3",
+        "cause": {
+          "type": "version_conflict_engine_exception",
+          "reason": "[3]: version conflict, document already exists (current version [1])",
+          "index": "movies-v2"
+        },
+        "status": 409
+      }]
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.total, 3);
+    assert_eq!(decoded.created, 2);
+    assert_eq!(decoded.version_conflicts, 1);
+    assert_eq!(decoded.failures.len(), 1);
+    let failure = &decoded.failures[0];
+    assert_eq!(failure.id.as_deref(), Some("3"));
+    assert_eq!(failure.status, Some(409));
+    assert_eq!(
+      failure.cause.as_ref().unwrap().kind,
+      "version_conflict_engine_exception"
+    );
+  }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use super::ErrorCause;
+
+/// Shards a request ran on, found in the `_shards` object of search, count,
+/// bulk and document responses.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ShardStats {
+  #[serde(default)]
+  pub total: u32,
+  #[serde(default)]
+  pub successful: u32,
+  #[serde(default)]
+  pub skipped: u32,
+  #[serde(default)]
+  pub failed: u32,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub failures: Vec<ShardFailure>,
+}
+
+impl ShardStats {
+  /// Whether the request failed on any shard, in which case its results are
+  /// partial
+  pub fn has_failures(&self) -> bool {
+    self.failed > 0 || !self.failures.is_empty()
+  }
+}
+
+/// Shard a request failed on.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ShardFailure {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub index: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub shard: Option<u32>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub node: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub reason: Option<ErrorCause>,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_decode_shard_failure() {
+    let decoded: ShardStats = serde_json::from_value(json!({
+      "total": 2,
+      "successful": 1,
+      "skipped": 0,
+      "failed": 1,
+      "failures": [{
+        "shard": 1,
+        "index": "movies",
+        "node": "kTbNOrbFQXqzE6iRQYLihg",
+        "reason": {
+          "type": "query_shard_exception",
+          "reason": "failed to create query: For input string: \"drama\"",
+          "index": "movies",
+          "index_uuid": "xkX2TwyxQlST0KwAP6E4Jw"
+        }
+      }]
+    }))
+    .unwrap();
+
+    assert_eq!(decoded.total, 2);
+    assert_eq!(decoded.failed, 1);
+    assert!(decoded.has_failures());
+    let failure = &decoded.failures[0];
+    assert_eq!(failure.index.as_deref(), Some("movies"));
+    assert_eq!(failure.shard, Some(1));
+    assert_eq!(failure.reason.as_ref().unwrap().kind, "query_shard_exception");
+  }
+
+  #[test]
+  fn test_decode_without_failures() {
+    let decoded: ShardStats =
+      serde_json::from_value(json!({ "total": 1, "successful": 1, "skipped": 0, "failed": 0 })).unwrap();
+
+    assert!(!decoded.has_failures());
+    assert!(decoded.failures.is_empty());
+  }
+}
@@ -88,6 +88,17 @@ macro_rules! deserialize_with_root {
       where
         D: $crate::serde::Deserializer<'de>, {
         use std::fmt;
+
+        struct Wrapper($inner);
+
+        impl<'de> $crate::serde::Deserialize<'de> for Wrapper {
+          fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+          where
+            D: $crate::serde::Deserializer<'de>, {
+            <$inner>::deserialize(deserializer).map(Wrapper)
+          }
+        }
+
         struct WrapperVisitor;
 
         impl<'de> $crate::serde::de::Visitor<'de> for WrapperVisitor {
@@ -104,7 +115,7 @@ macro_rules! deserialize_with_root {
 
             while let Some(key) = map.next_key::<String>()? {
               if key == $root {
-                value = Some(map.next_value()?);
+                value = Some(map.next_value::<Wrapper>()?.0);
               }
             }
 
@@ -212,6 +223,51 @@ macro_rules! deserialize_with_root_keyed {
       where
         D: $crate::serde::Deserializer<'de>, {
         use std::fmt;
+
+        struct Inner($inner);
+
+        impl<'de> $crate::serde::Deserialize<'de> for Inner {
+          fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+          where
+            D: $crate::serde::Deserializer<'de>, {
+            <$inner>::deserialize(deserializer).map(Inner)
+          }
+        }
+
+        // Reads the `{ "<field>": { .. } }` object written by
+        // `serialize_with_root_keyed!`, restoring the field from its key
+        struct Keyed($inner);
+
+        impl<'de> $crate::serde::Deserialize<'de> for Keyed {
+          fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+          where
+            D: $crate::serde::Deserializer<'de>, {
+            struct KeyedVisitor;
+
+            impl<'de> $crate::serde::de::Visitor<'de> for KeyedVisitor {
+              type Value = Keyed;
+
+              fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an object keyed by field name")
+              }
+
+              fn visit_map<A>(self, mut map: A) -> Result<Keyed, A::Error>
+              where
+                A: $crate::serde::de::MapAccess<'de>, {
+                let field = map
+                  .next_key::<String>()?
+                  .ok_or_else(|| $crate::serde::de::Error::invalid_length(0, &self))?;
+                let Inner(mut value) = map.next_value()?;
+                value.field = field;
+
+                Ok(Keyed(value))
+              }
+            }
+
+            deserializer.deserialize_map(KeyedVisitor)
+          }
+        }
+
         struct WrapperVisitor;
 
         impl<'de> $crate::serde::de::Visitor<'de> for WrapperVisitor {
@@ -228,7 +284,7 @@ macro_rules! deserialize_with_root_keyed {
 
             while let Some(key) = map.next_key::<String>()? {
               if key == $root {
-                value = Some(map.next_value()?);
+                value = Some(map.next_value::<Keyed>()?.0);
               }
             }
 
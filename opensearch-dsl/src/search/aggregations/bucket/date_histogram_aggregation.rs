@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 use crate::{search::*, util::*};
 
@@ -29,6 +30,12 @@ struct DateHistogramAggregationInner {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   fixed_interval: Option<Time>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  format: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  extended_bounds: Option<DateHistogramBounds>,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   min_doc_count: Option<u32>,
 
@@ -45,6 +52,15 @@ struct DateHistogramAggregationInner {
   order: TermsOrderCollection,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct DateHistogramBounds {
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  min: Option<Term>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  max: Option<Term>,
+}
+
 impl Aggregation {
   /// Creates an instance of [`DateHistogramAggregation`]
   ///
@@ -57,6 +73,8 @@ impl Aggregation {
         field: field.to_string(),
         calendar_interval: None,
         fixed_interval: None,
+        format: None,
+        extended_bounds: None,
         min_doc_count: None,
         missing: None,
         offset: None,
@@ -73,8 +91,11 @@ impl DateHistogramAggregation {
 
   /// Calendar-aware intervals are configured with the calendar_interval
   /// parameter
+  ///
+  /// Replaces any previously set [`fixed_interval`](Self::fixed_interval)
   pub fn calendar_interval(mut self, calendar_interval: CalendarInterval) -> Self {
     self.date_histogram.calendar_interval = Some(calendar_interval);
+    self.date_histogram.fixed_interval = None;
     self
   }
 
@@ -82,8 +103,38 @@ impl DateHistogramAggregation {
   /// number of SI units and never deviate, regardless of where they fall on
   /// the calendar. One second is always composed of 1000ms. This allows fixed
   /// intervals to be specified in any multiple of the supported units.
+  ///
+  /// Replaces any previously set
+  /// [`calendar_interval`](Self::calendar_interval)
   pub fn fixed_interval(mut self, fixed_interval: Time) -> Self {
     self.date_histogram.fixed_interval = Some(fixed_interval);
+    self.date_histogram.calendar_interval = None;
+    self
+  }
+
+  /// Date format used to render the bucket keys as `key_as_string` and to
+  /// parse the [`extended_bounds`](Self::extended_bounds)
+  pub fn format<T>(mut self, format: T) -> Self
+  where
+    T: ToString, {
+    self.date_histogram.format = Some(format.to_string());
+    self
+  }
+
+  /// Forces the histogram to start building buckets at `min` and keep
+  /// building them up to `max`, even if no documents fall into them. Only
+  /// has an effect on empty buckets when `min_doc_count` is `0`.
+  ///
+  /// Bounds can be dates, epoch millis or date math expressions such as
+  /// `now-1y/d`.
+  pub fn extended_bounds<T, U>(mut self, min: T, max: U) -> Self
+  where
+    T: Serialize,
+    U: Serialize, {
+    self.date_histogram.extended_bounds = Some(DateHistogramBounds {
+      min: Term::new(min),
+      max: Term::new(max),
+    });
     self
   }
 
@@ -165,7 +216,6 @@ mod tests {
     assert_serialize_aggregation(
       Aggregation::date_histogram("test_field")
         .calendar_interval(CalendarInterval::Day)
-        .fixed_interval(Time::Hours(1))
         .min_doc_count(2)
         .missing(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 4).single().unwrap())
         .order(TermsOrder::new("test_order", SortOrder::Asc))
@@ -175,7 +225,6 @@ mod tests {
           "date_histogram": {
               "field": "test_field",
               "calendar_interval": "day",
-              "fixed_interval": "1h",
               "min_doc_count": 2,
               "missing": "2014-11-28T12:00:04Z",
               "order": [
@@ -203,4 +252,57 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_fixed_interval_with_bounds() {
+    assert_serialize_aggregation(
+      Aggregation::date_histogram("@timestamp")
+        .fixed_interval(Time::Minutes(30))
+        .format("yyyy-MM-dd HH:mm")
+        .min_doc_count(0)
+        .extended_bounds("now-1d/d", "now/d"),
+      json!({
+          "date_histogram": {
+              "field": "@timestamp",
+              "fixed_interval": "30m",
+              "format": "yyyy-MM-dd HH:mm",
+              "min_doc_count": 0,
+              "extended_bounds": { "min": "now-1d/d", "max": "now/d" }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_calendar_interval_with_time_zone() {
+    assert_serialize_aggregation(
+      Aggregation::date_histogram("@timestamp")
+        .calendar_interval(CalendarInterval::Month)
+        .time_zone("Europe/Amsterdam"),
+      json!({
+          "date_histogram": {
+              "field": "@timestamp",
+              "calendar_interval": "month",
+              "time_zone": "Europe/Amsterdam"
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn intervals_are_mutually_exclusive() {
+    assert_serialize_aggregation(
+      Aggregation::date_histogram("@timestamp")
+        .fixed_interval(Time::Hours(1))
+        .calendar_interval(CalendarInterval::Week),
+      json!({ "date_histogram": { "field": "@timestamp", "calendar_interval": "week" } }),
+    );
+
+    assert_serialize_aggregation(
+      Aggregation::date_histogram("@timestamp")
+        .calendar_interval(CalendarInterval::Week)
+        .fixed_interval(Time::Hours(1)),
+      json!({ "date_histogram": { "field": "@timestamp", "fixed_interval": "1h" } }),
+    );
+  }
 }
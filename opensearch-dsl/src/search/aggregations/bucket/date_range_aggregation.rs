@@ -0,0 +1,191 @@
+use serde::Serialize;
+
+use crate::{search::*, util::*};
+
+/// A range aggregation that is dedicated for date values. The main difference
+/// between this aggregation and the normal range aggregation is that the
+/// `from` and `to` values can be expressed in
+/// [date math](https://opensearch.org/docs/latest/field-types/supported-field-types/date/#date-math)
+/// expressions, and it is also possible to specify a date format by which the
+/// `from` and `to` response fields will be returned.
+///
+/// <https://opensearch.org/docs/latest/aggregations/bucket/date-range/>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DateRangeAggregation {
+  date_range: DateRangeAggregationInner,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct DateRangeAggregationInner {
+  field: String,
+
+  ranges: Vec<DateAggregationRange>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  format: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  time_zone: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  keyed: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  missing: Option<Term>,
+}
+
+/// A single bucket of a [`DateRangeAggregation`]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DateAggregationRange {
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  key: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  from: Option<Term>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  to: Option<Term>,
+}
+
+impl Aggregation {
+  /// Creates an instance of [`DateRangeAggregation`]
+  ///
+  /// - `field` - date field to bucket
+  pub fn date_range<T>(field: T) -> DateRangeAggregation
+  where
+    T: ToString, {
+    DateRangeAggregation {
+      date_range: DateRangeAggregationInner {
+        field: field.to_string(),
+        ranges: vec![],
+        format: None,
+        time_zone: None,
+        keyed: None,
+        missing: None,
+      },
+      aggs: Aggregations::new(),
+    }
+  }
+}
+
+impl DateRangeAggregation {
+  add_aggregate!();
+
+  /// Adds a bucket range
+  pub fn range(mut self, range: DateAggregationRange) -> Self {
+    self.date_range.ranges.push(range);
+    self
+  }
+
+  /// Adds multiple bucket ranges
+  pub fn ranges<I>(mut self, ranges: I) -> Self
+  where
+    I: IntoIterator<Item = DateAggregationRange>, {
+    self.date_range.ranges.extend(ranges);
+    self
+  }
+
+  /// Date format used to parse the `from` and `to` values and to render the
+  /// bucket boundaries in the response
+  pub fn format<T>(mut self, format: T) -> Self
+  where
+    T: ToString, {
+    self.date_range.format = Some(format.to_string());
+    self
+  }
+
+  /// Time zone used to convert date math expressions and dates without an
+  /// offset to UTC
+  pub fn time_zone<T>(mut self, time_zone: T) -> Self
+  where
+    T: ToString, {
+    self.date_range.time_zone = Some(time_zone.to_string());
+    self
+  }
+
+  /// Setting the `keyed` flag to `true` associates a unique string key with
+  /// each bucket and returns the ranges as a hash rather than an array.
+  pub fn keyed(mut self, keyed: bool) -> Self {
+    self.date_range.keyed = Some(keyed);
+    self
+  }
+
+  /// The missing parameter defines how documents that are missing a value
+  /// should be treated. By default they will be ignored but it is also
+  /// possible to treat them as if they had a value.
+  pub fn missing<T>(mut self, missing: T) -> Self
+  where
+    T: Serialize, {
+    self.date_range.missing = Term::new(missing);
+    self
+  }
+}
+
+impl DateAggregationRange {
+  /// Creates an unbounded instance of [`DateAggregationRange`]
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// Custom key returned for this bucket
+  pub fn key<T>(mut self, key: T) -> Self
+  where
+    T: ToString, {
+    self.key = Some(key.to_string());
+    self
+  }
+
+  /// Lower bound of the bucket, inclusive. Accepts dates, epoch millis or
+  /// date math expressions such as `now-10M/M`.
+  pub fn from<T>(mut self, from: T) -> Self
+  where
+    T: Serialize, {
+    self.from = Term::new(from);
+    self
+  }
+
+  /// Upper bound of the bucket, exclusive. Accepts dates, epoch millis or
+  /// date math expressions such as `now-10M/M`.
+  pub fn to<T>(mut self, to: T) -> Self
+  where
+    T: Serialize, {
+    self.to = Term::new(to);
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::prelude::*;
+
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_aggregation(
+      Aggregation::date_range("date")
+        .format("MM-yyyy")
+        .time_zone("CET")
+        .range(DateAggregationRange::new().to("now-10M/M"))
+        .range(
+          DateAggregationRange::new()
+            .key("recent")
+            .from(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 4).single().unwrap()),
+        ),
+      json!({
+          "date_range": {
+              "field": "date",
+              "format": "MM-yyyy",
+              "time_zone": "CET",
+              "ranges": [
+                  { "to": "now-10M/M" },
+                  { "key": "recent", "from": "2014-11-28T12:00:04Z" }
+              ]
+          }
+      }),
+    );
+  }
+}
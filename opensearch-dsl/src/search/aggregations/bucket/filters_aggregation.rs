@@ -0,0 +1,84 @@
+use crate::{search::*, util::*, Map};
+
+/// A multi-bucket aggregation where each bucket contains the documents that
+/// match a query.
+///
+/// <https://opensearch.org/docs/latest/aggregations/bucket/filters/>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct FiltersAggregation {
+  filters: FiltersAggregationInner,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct FiltersAggregationInner {
+  filters: Map<String, Query>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  other_bucket_key: Option<String>,
+}
+
+impl Aggregation {
+  /// Creates an instance of [`FiltersAggregation`] with no buckets
+  pub fn filters() -> FiltersAggregation {
+    FiltersAggregation {
+      filters: FiltersAggregationInner {
+        filters: Map::new(),
+        other_bucket_key: None,
+      },
+      aggs: Aggregations::new(),
+    }
+  }
+}
+
+impl FiltersAggregation {
+  add_aggregate!();
+
+  /// Adds a named bucket containing the documents matching `query`
+  pub fn filter<T, Q>(mut self, key: T, query: Q) -> Self
+  where
+    T: ToString,
+    Q: Into<Query>, {
+    let _ = self.filters.filters.insert(key.to_string(), query.into());
+    self
+  }
+
+  /// Adds a bucket with the given key for documents that don't match any of
+  /// the filters
+  pub fn other_bucket_key<T>(mut self, other_bucket_key: T) -> Self
+  where
+    T: ToString, {
+    self.filters.other_bucket_key = Some(other_bucket_key.to_string());
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_aggregation(
+      Aggregation::filters()
+        .filter("errors", Query::r#match("body", "error"))
+        .filter("warnings", Query::r#match("body", "warning"))
+        .other_bucket_key("other_messages")
+        .aggregate("monthly", Aggregation::terms("month")),
+      json!({
+          "filters": {
+              "filters": {
+                  "errors": { "match": { "body": { "query": "error" } } },
+                  "warnings": { "match": { "body": { "query": "warning" } } }
+              },
+              "other_bucket_key": "other_messages"
+          },
+          "aggs": {
+              "monthly": { "terms": { "field": "month" } }
+          }
+      }),
+    );
+  }
+}
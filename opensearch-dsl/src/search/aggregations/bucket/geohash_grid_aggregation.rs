@@ -0,0 +1,122 @@
+use crate::{search::*, util::*};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// A multi-bucket aggregation that groups geo_point and geo_shape values into
+/// buckets that represent a grid of geohash cells. Each cell is labeled using
+/// a geohash whose length is equal to the user-specified precision.
+///
+/// <https://opensearch.org/docs/latest/aggregations/bucket/geohash-grid/>
+pub struct GeoHashGridAggregation {
+  geohash_grid: GeoHashGridAggregationInner,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct GeoHashGridAggregationInner {
+  field: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  precision: Option<u8>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  bounds: Option<GeoBoundingBox>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  size: Option<u64>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  shard_size: Option<u64>,
+}
+
+impl Aggregation {
+  /// Creates an instance of [`GeoHashGridAggregation`]
+  ///
+  /// - `field` - field to group by
+  pub fn geohash_grid<T>(field: T) -> GeoHashGridAggregation
+  where
+    T: ToString, {
+    GeoHashGridAggregation {
+      geohash_grid: GeoHashGridAggregationInner {
+        field: field.to_string(),
+        precision: None,
+        bounds: None,
+        size: None,
+        shard_size: None,
+      },
+      aggs: Aggregations::new(),
+    }
+  }
+}
+
+impl GeoHashGridAggregation {
+  add_aggregate!();
+
+  /// The maximum number of geohash buckets to return. Defaults to 10,000.
+  pub fn size(mut self, size: u64) -> Self {
+    self.geohash_grid.size = Some(size);
+    self
+  }
+
+  /// The maximum number of buckets returned from each shard. Defaults to
+  /// max(10,(size x number-of-shards)).
+  pub fn shard_size(mut self, shard_size: u64) -> Self {
+    self.geohash_grid.shard_size = Some(shard_size);
+    self
+  }
+
+  /// The length of the geohash used to define cells/buckets in the results.
+  /// Defaults to 5. Values outside of \[1,12\] will be rejected.
+  pub fn precision(mut self, precision: u8) -> Self {
+    self.geohash_grid.precision = Some(precision);
+    self
+  }
+
+  /// the `bounds` parameter defines the bounding box used to filter the
+  /// geo-points or geo-shapes in each bucket. Accepts the same bounding box
+  /// formats as the [`GeoBoundingBoxQuery`]
+  pub fn bounds<T>(mut self, bounds: T) -> Self
+  where
+    T: Into<GeoBoundingBox>, {
+    self.geohash_grid.bounds = Some(bounds.into());
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_aggregation(
+      Aggregation::geohash_grid("location"),
+      json!({ "geohash_grid": { "field": "location" } }),
+    );
+
+    assert_serialize_aggregation(
+      Aggregation::geohash_grid("location")
+        .precision(5)
+        .size(100)
+        .bounds(GeoBoundingBox::edges(52.4, 4.8, 52.3, 5.0))
+        .aggregate("max_rating", Aggregation::max("rating")),
+      json!({
+          "geohash_grid": {
+              "field": "location",
+              "precision": 5,
+              "size": 100,
+              "bounds": {
+                  "top": 52.4,
+                  "left": 4.8,
+                  "bottom": 52.3,
+                  "right": 5.0
+              }
+          },
+          "aggs": {
+              "max_rating": { "max": { "field": "rating" } }
+          }
+      }),
+    );
+  }
+}
@@ -0,0 +1,142 @@
+use crate::{search::*, util::*};
+
+/// A multi-bucket values source based aggregation that can be applied on
+/// numeric values or numeric range values extracted from the documents. It
+/// dynamically builds fixed size (a.k.a. interval) buckets over the values.
+///
+/// <https://opensearch.org/docs/latest/aggregations/bucket/histogram/>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct HistogramAggregation {
+  histogram: HistogramAggregationInner,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct HistogramAggregationInner {
+  field: String,
+
+  interval: Number,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  min_doc_count: Option<u32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  offset: Option<Number>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  missing: Option<Number>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  keyed: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  order: TermsOrderCollection,
+}
+
+impl Aggregation {
+  /// Creates an instance of [`HistogramAggregation`]
+  ///
+  /// - `field` - numeric field to group by
+  /// - `interval` - size of each bucket
+  pub fn histogram<T, U>(field: T, interval: U) -> HistogramAggregation
+  where
+    T: ToString,
+    U: Into<Number>, {
+    HistogramAggregation {
+      histogram: HistogramAggregationInner {
+        field: field.to_string(),
+        interval: interval.into(),
+        min_doc_count: None,
+        offset: None,
+        missing: None,
+        keyed: None,
+        order: Default::default(),
+      },
+      aggs: Aggregations::new(),
+    }
+  }
+}
+
+impl HistogramAggregation {
+  add_aggregate!();
+
+  /// Only returns buckets that have at least `min_doc_count` documents.
+  ///
+  /// Default value is `0`
+  pub fn min_doc_count(mut self, min_doc_count: u32) -> Self {
+    self.histogram.min_doc_count = Some(min_doc_count);
+    self
+  }
+
+  /// Shifts the bucket boundaries by the given value. By default the bucket
+  /// keys start with 0 and then continue in even spaced steps of `interval`.
+  pub fn offset<T>(mut self, offset: T) -> Self
+  where
+    T: Into<Number>, {
+    self.histogram.offset = Some(offset.into());
+    self
+  }
+
+  /// The missing parameter defines how documents that are missing a value
+  /// should be treated. By default they will be ignored but it is also
+  /// possible to treat them as if they had a value.
+  pub fn missing<T>(mut self, missing: T) -> Self
+  where
+    T: Into<Number>, {
+    self.histogram.missing = Some(missing.into());
+    self
+  }
+
+  /// Setting the `keyed` flag to `true` associates a unique string key with
+  /// each bucket and returns the buckets as a hash rather than an array.
+  pub fn keyed(mut self, keyed: bool) -> Self {
+    self.histogram.keyed = Some(keyed);
+    self
+  }
+
+  /// The order of the buckets can be customized by setting the order
+  /// parameter. By default, the buckets are ordered by their key ascending.
+  pub fn order<T>(mut self, order: T) -> Self
+  where
+    T: Into<TermsOrderCollection>, {
+    self.histogram.order = order.into();
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_aggregation(
+      Aggregation::histogram("price", 50),
+      json!({ "histogram": { "field": "price", "interval": 50 } }),
+    );
+
+    assert_serialize_aggregation(
+      Aggregation::histogram("price", 2.5)
+        .min_doc_count(1)
+        .offset(1)
+        .missing(0)
+        .keyed(true)
+        .aggregate("avg_rating", Aggregation::avg("rating")),
+      json!({
+          "histogram": {
+              "field": "price",
+              "interval": 2.5,
+              "min_doc_count": 1,
+              "offset": 1,
+              "missing": 0,
+              "keyed": true
+          },
+          "aggs": {
+              "avg_rating": { "avg": { "field": "rating" } }
+          }
+      }),
+    );
+  }
+}
@@ -27,10 +27,15 @@ mod bucket_selector_aggregation;
 mod children_aggregation;
 mod composite_aggregation;
 mod date_histogram_aggregation;
+mod date_range_aggregation;
 mod diversified_sampler_aggregation;
 mod filter_aggregation;
+mod filters_aggregation;
+mod geohash_grid_aggregation;
 mod geotile_grid_aggregation;
+mod histogram_aggregation;
 mod nested_aggregation;
+mod range_aggregation;
 mod sampler_aggregation;
 mod terms_aggregation;
 
@@ -38,9 +43,14 @@ pub use self::bucket_selector_aggregation::*;
 pub use self::children_aggregation::*;
 pub use self::composite_aggregation::*;
 pub use self::date_histogram_aggregation::*;
+pub use self::date_range_aggregation::*;
 pub use self::diversified_sampler_aggregation::*;
 pub use self::filter_aggregation::*;
+pub use self::filters_aggregation::*;
+pub use self::geohash_grid_aggregation::*;
 pub use self::geotile_grid_aggregation::*;
+pub use self::histogram_aggregation::*;
 pub use self::nested_aggregation::*;
+pub use self::range_aggregation::*;
 pub use self::sampler_aggregation::*;
 pub use self::terms_aggregation::*;
@@ -0,0 +1,161 @@
+use crate::{search::*, util::*};
+
+/// A multi-bucket value source based aggregation that enables the user to
+/// define a set of ranges - each representing a bucket. During the
+/// aggregation process, the values extracted from each document will be
+/// checked against each bucket range and "bucket" the relevant/matching
+/// document. Note that this aggregation includes the `from` value and
+/// excludes the `to` value for each range.
+///
+/// <https://opensearch.org/docs/latest/aggregations/bucket/range/>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct RangeAggregation {
+  range: RangeAggregationInner,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+struct RangeAggregationInner {
+  field: String,
+
+  ranges: Vec<AggregationRange>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  keyed: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  missing: Option<Number>,
+}
+
+/// A single bucket of a [`RangeAggregation`]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+pub struct AggregationRange {
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  key: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  from: Option<Number>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  to: Option<Number>,
+}
+
+impl Aggregation {
+  /// Creates an instance of [`RangeAggregation`]
+  ///
+  /// - `field` - numeric field to bucket
+  pub fn range<T>(field: T) -> RangeAggregation
+  where
+    T: ToString, {
+    RangeAggregation {
+      range: RangeAggregationInner {
+        field: field.to_string(),
+        ranges: vec![],
+        keyed: None,
+        missing: None,
+      },
+      aggs: Aggregations::new(),
+    }
+  }
+}
+
+impl RangeAggregation {
+  add_aggregate!();
+
+  /// Adds a bucket range
+  pub fn range(mut self, range: AggregationRange) -> Self {
+    self.range.ranges.push(range);
+    self
+  }
+
+  /// Adds multiple bucket ranges
+  pub fn ranges<I>(mut self, ranges: I) -> Self
+  where
+    I: IntoIterator<Item = AggregationRange>, {
+    self.range.ranges.extend(ranges);
+    self
+  }
+
+  /// Setting the `keyed` flag to `true` associates a unique string key with
+  /// each bucket and returns the ranges as a hash rather than an array.
+  pub fn keyed(mut self, keyed: bool) -> Self {
+    self.range.keyed = Some(keyed);
+    self
+  }
+
+  /// The missing parameter defines how documents that are missing a value
+  /// should be treated. By default they will be ignored but it is also
+  /// possible to treat them as if they had a value.
+  pub fn missing<T>(mut self, missing: T) -> Self
+  where
+    T: Into<Number>, {
+    self.range.missing = Some(missing.into());
+    self
+  }
+}
+
+impl AggregationRange {
+  /// Creates an unbounded instance of [`AggregationRange`]
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// Custom key returned for this bucket
+  pub fn key<T>(mut self, key: T) -> Self
+  where
+    T: ToString, {
+    self.key = Some(key.to_string());
+    self
+  }
+
+  /// Lower bound of the bucket, inclusive
+  pub fn from<T>(mut self, from: T) -> Self
+  where
+    T: Into<Number>, {
+    self.from = Some(from.into());
+    self
+  }
+
+  /// Upper bound of the bucket, exclusive
+  pub fn to<T>(mut self, to: T) -> Self
+  where
+    T: Into<Number>, {
+    self.to = Some(to.into());
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_aggregation(
+      Aggregation::range("price")
+        .range(AggregationRange::new().to(100))
+        .ranges([
+          AggregationRange::new().from(100).to(200),
+          AggregationRange::new().key("expensive").from(200),
+        ])
+        .keyed(true)
+        .aggregate("avg_rating", Aggregation::avg("rating")),
+      json!({
+          "range": {
+              "field": "price",
+              "ranges": [
+                  { "to": 100 },
+                  { "from": 100, "to": 200 },
+                  { "key": "expensive", "from": 200 }
+              ],
+              "keyed": true
+          },
+          "aggs": {
+              "avg_rating": { "avg": { "field": "rating" } }
+          }
+      }),
+    );
+  }
+}
@@ -22,6 +22,9 @@ struct TermsAggregationInner {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   size: Option<u64>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  shard_size: Option<u64>,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   show_term_doc_count_error: Option<bool>,
 
@@ -56,6 +59,7 @@ impl Aggregation {
             terms: TermsAggregationInner {
                 field: Some(field.to_string()),
                 size: None,
+                shard_size: None,
                 show_term_doc_count_error: None,
                 order: Default::default(),
                 min_doc_count: None,
@@ -74,6 +78,7 @@ impl Aggregation {
             terms: TermsAggregationInner {
                 field: None,
                 size: None,
+                shard_size: None,
                 show_term_doc_count_error: None,
                 order: Default::default(),
                 min_doc_count: None,
@@ -100,6 +105,15 @@ impl TermsAggregation {
         self
     }
 
+    /// The `shard_size` parameter controls how many term buckets each shard returns to the coordinating node.
+    ///
+    /// Requesting more buckets per shard than `size` reduces the error on document counts at the cost of more
+    /// memory and network traffic. It cannot be smaller than `size`.
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.terms.shard_size = Some(shard_size);
+        self
+    }
+
     /// Shows an error value for each term returned by the aggregation which represents the worst case error in the document
     /// count and can be useful when deciding on a value for the shard_size parameter.
     /// This is calculated by summing the document counts for the last term returned by all shards which did not return the term.
@@ -272,4 +286,62 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_order_by_sub_aggregation() {
+        assert_serialize_aggregation(
+            Aggregation::terms("genre")
+                .size(5)
+                .shard_size(25)
+                .order(TermsOrder::descending("avg_rating"))
+                .aggregate("avg_rating", Aggregation::avg("rating")),
+            json!({
+                "terms": {
+                    "field": "genre",
+                    "size": 5,
+                    "shard_size": 25,
+                    "order": [
+                        { "avg_rating": "desc" }
+                    ]
+                },
+                "aggs": {
+                    "avg_rating": { "avg": { "field": "rating" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_include_regex_and_exclude_terms() {
+        assert_serialize_aggregation(
+            Aggregation::terms("tags")
+                .include(".*sport.*")
+                .exclude(["water_sports", "e_sports"]),
+            json!({
+                "terms": {
+                    "field": "tags",
+                    "include": ".*sport.*",
+                    "exclude": ["water_sports", "e_sports"]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_nested_avg_sub_aggregation() {
+        assert_serialize_aggregation(
+            Aggregation::terms("genre")
+                .size(10)
+                .aggregate("avg_rating", Aggregation::avg("rating")),
+            json!({
+                "terms": {
+                    "field": "genre",
+                    "size": 10
+                },
+                "aggs": {
+                    "avg_rating": { "avg": { "field": "rating" } }
+                }
+            }),
+        );
+    }
 }
@@ -75,10 +75,15 @@ aggregation!(
     Boxplot(BoxplotAggregation),
     DateHistogram(DateHistogramAggregation),
     GeotileGrid(GeotileGridAggregation),
+    GeoHashGrid(GeoHashGridAggregation),
     BucketSelector(BucketSelectorAggregation),
     Children(ChildrenAggregation),
     Composite(CompositeAggregation),
     Nested(NestedAggregation),
+    Histogram(HistogramAggregation),
+    Range(RangeAggregation),
+    DateRange(DateRangeAggregation),
+    Filters(FiltersAggregation),
 );
 
 /// Type alias for a collection of aggregations
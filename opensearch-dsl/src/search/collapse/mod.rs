@@ -9,10 +9,10 @@ use crate::{util::*, *};
 pub struct Collapse {
   field: String,
 
-  #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   inner_hits: Vec<InnerHits>,
 
-  #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   max_concurrent_group_searches: Option<u64>,
 }
 
@@ -106,4 +106,25 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn round_trip() {
+    let collapses = [
+      Collapse::new("user.id"),
+      Collapse::new("user.id")
+        .max_concurrent_group_searches(4)
+        .inner_hits([InnerHits::new()
+          .name("most_recent")
+          .size(5)
+          .sort([FieldSort::descending("@timestamp")])
+          .collapse("user.id")]),
+    ];
+
+    for collapse in collapses {
+      let value = serde_json::to_value(&collapse).unwrap();
+      let deserialized: Collapse = serde_json::from_value(value).unwrap();
+
+      assert_eq!(deserialized, collapse);
+    }
+  }
 }
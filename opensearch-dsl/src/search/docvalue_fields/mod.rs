@@ -0,0 +1,127 @@
+//! You can use the `docvalue_fields` parameter to return
+//! [doc values](https://opensearch.org/docs/latest/field-types/mapping-parameters/doc-values/)
+//! for one or more fields in the search response.
+//!
+//! <https://opensearch.org/docs/latest/search-plugins/searching-data/retrieve-specific-fields/#searching-with-docvalue_fields>
+
+/// A field to return from doc values, optionally rendered with a custom
+/// format
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DocValueField {
+  /// Field name or wildcard pattern, returned in its default format
+  Name(String),
+
+  /// Field name or wildcard pattern with a custom format, such as a date
+  /// format for `date` fields or a decimal pattern for numeric ones
+  WithFormat {
+    /// Field name or wildcard pattern
+    field: String,
+
+    /// Format applied to the returned values
+    format: String,
+  },
+}
+
+impl DocValueField {
+  /// Creates an instance of [`DocValueField`]
+  ///
+  /// - `field` - Field name or wildcard pattern to return
+  pub fn new<T>(field: T) -> Self
+  where
+    T: ToString, {
+    Self::Name(field.to_string())
+  }
+
+  /// Format applied to the returned values
+  pub fn format<T>(self, format: T) -> Self
+  where
+    T: ToString, {
+    let field = match self {
+      Self::Name(field) | Self::WithFormat { field, .. } => field,
+    };
+
+    Self::WithFormat {
+      field,
+      format: format.to_string(),
+    }
+  }
+}
+
+impl From<&str> for DocValueField {
+  fn from(value: &str) -> Self {
+    Self::new(value)
+  }
+}
+
+impl From<String> for DocValueField {
+  fn from(value: String) -> Self {
+    Self::Name(value)
+  }
+}
+
+impl From<&String> for DocValueField {
+  fn from(value: &String) -> Self {
+    Self::new(value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{util::assert_serialize, InnerHits, Search};
+
+  #[test]
+  fn serializes_names_and_formats() {
+    assert_serialize(
+      Search::new()
+        .docvalue_fields(["my_ip*", "user.id"])
+        .docvalue_field(DocValueField::new("@timestamp").format("epoch_millis")),
+      json!({
+          "docvalue_fields": [
+              "my_ip*",
+              "user.id",
+              { "field": "@timestamp", "format": "epoch_millis" }
+          ]
+      }),
+    );
+  }
+
+  #[test]
+  fn keeps_insertion_order() {
+    assert_serialize(
+      Search::new()
+        .docvalue_field(DocValueField::new("released").format("yyyy"))
+        .docvalue_fields(["title", "genre"]),
+      json!({
+          "docvalue_fields": [
+              { "field": "released", "format": "yyyy" },
+              "title",
+              "genre"
+          ]
+      }),
+    );
+
+    assert_serialize(
+      InnerHits::new().docvalue_fields(["title", "genre"]),
+      json!({ "docvalue_fields": ["title", "genre"] }),
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    let fields = vec![
+      DocValueField::new("user.id"),
+      DocValueField::new("created_at").format("yyyy-MM-dd"),
+    ];
+
+    let value = serde_json::to_value(&fields).unwrap();
+    assert_eq!(
+      value,
+      json!(["user.id", { "field": "created_at", "format": "yyyy-MM-dd" }])
+    );
+
+    let deserialized: Vec<DocValueField> = serde_json::from_value(value).unwrap();
+    assert_eq!(deserialized, fields);
+  }
+}
@@ -0,0 +1,130 @@
+//! You can use the `fields` parameter to retrieve formatted values for
+//! specific fields, including runtime fields and multi-fields, independently
+//! of `_source`.
+//!
+//! <https://opensearch.org/docs/latest/search-plugins/searching-data/retrieve-specific-fields/#searching-with-fields>
+
+use crate::util::*;
+
+/// A field to retrieve with the `fields` parameter
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum FieldAndFormat {
+  /// Field name or wildcard pattern, returned in its default format
+  Pattern(String),
+
+  /// Field name or wildcard pattern with additional options
+  WithOptions {
+    /// Field name or wildcard pattern
+    field: String,
+
+    /// Format applied to the returned values
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    /// Whether to return unmapped fields found in `_source` that match the
+    /// pattern
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    include_unmapped: Option<bool>,
+  },
+}
+
+impl FieldAndFormat {
+  /// Creates an instance of [`FieldAndFormat`]
+  ///
+  /// - `field` - Field name or wildcard pattern to retrieve
+  pub fn new<T>(field: T) -> Self
+  where
+    T: ToString, {
+    Self::Pattern(field.to_string())
+  }
+
+  /// Format applied to the returned values
+  pub fn format<T>(self, format: T) -> Self
+  where
+    T: ToString, {
+    let (field, _, include_unmapped) = self.into_parts();
+
+    Self::WithOptions {
+      field,
+      format: Some(format.to_string()),
+      include_unmapped,
+    }
+  }
+
+  /// Whether to return unmapped fields found in `_source` that match the
+  /// pattern
+  pub fn include_unmapped(self, include_unmapped: bool) -> Self {
+    let (field, format, _) = self.into_parts();
+
+    Self::WithOptions {
+      field,
+      format,
+      include_unmapped: Some(include_unmapped),
+    }
+  }
+
+  fn into_parts(self) -> (String, Option<String>, Option<bool>) {
+    match self {
+      Self::Pattern(field) => (field, None, None),
+      Self::WithOptions {
+        field,
+        format,
+        include_unmapped,
+      } => (field, format, include_unmapped),
+    }
+  }
+}
+
+impl From<&str> for FieldAndFormat {
+  fn from(value: &str) -> Self {
+    Self::new(value)
+  }
+}
+
+impl From<String> for FieldAndFormat {
+  fn from(value: String) -> Self {
+    Self::Pattern(value)
+  }
+}
+
+impl From<&String> for FieldAndFormat {
+  fn from(value: &String) -> Self {
+    Self::new(value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{util::assert_serialize, Search};
+
+  #[test]
+  fn serializes_wildcard_pattern() {
+    assert_serialize(
+      Search::new().source(false).fields(["user.*"]),
+      json!({
+          "_source": false,
+          "fields": ["user.*"]
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_explicit_format() {
+    assert_serialize(
+      Search::new().fields([
+        FieldAndFormat::new("user.id"),
+        FieldAndFormat::new("@timestamp").format("epoch_millis"),
+        FieldAndFormat::new("labels.*").include_unmapped(true),
+      ]),
+      json!({
+          "fields": [
+              "user.id",
+              { "field": "@timestamp", "format": "epoch_millis" },
+              { "field": "labels.*", "include_unmapped": true }
+          ]
+      }),
+    );
+  }
+}
@@ -104,6 +104,12 @@ impl Highlight {
   }
 }
 
+impl ShouldSkip for Highlight {
+  fn should_skip(&self) -> bool {
+    self.fields.is_empty()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -190,4 +196,51 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn field_options_override_global_options() {
+    assert_serialize(
+      Highlight::new()
+        .highlighter(
+          Highlighter::new()
+            .tags((["<em>"], ["</em>"]))
+            .fragment_size(150)
+            .number_of_fragments(3)
+            .require_field_match(false),
+        )
+        .field_highlighter(
+          "comment",
+          Highlighter::new().tags((["<mark>"], ["</mark>"])).fragment_size(50),
+        ),
+      json!({
+          "pre_tags": ["<em>"],
+          "post_tags": ["</em>"],
+          "fragment_size": 150,
+          "number_of_fragments": 3,
+          "require_field_match": false,
+          "fields": [
+              {
+                  "comment": {
+                      "pre_tags": ["<mark>"],
+                      "post_tags": ["</mark>"],
+                      "fragment_size": 50
+                  }
+              }
+          ]
+      }),
+    );
+  }
+
+  #[test]
+  fn skipped_in_search_without_fields() {
+    assert_serialize(
+      crate::Search::new().highlight(Highlight::new().highlighter(Highlighter::new().tags((["<em>"], ["</em>"])))),
+      json!({}),
+    );
+
+    assert_serialize(
+      crate::Search::new().highlight(Highlight::new().field("title")),
+      json!({ "highlight": { "fields": [{ "title": {} }] } }),
+    );
+  }
 }
@@ -29,9 +29,10 @@ use crate::{search::*, util::*};
 ///
 /// To create a knn search with a query vector or query vector builder:
 /// ```
-/// # use elasticsearch_dsl::*;
+/// # use opensearch_dsl::*;
 /// # let search =
 /// Search::new()
+///   .knn(KnnSearch::new("embedding", vec![0.1, 0.2, 0.3], 10))
 ///   .knn(Knn::query_vector("test1", vec![1.0, 2.0, 3.0]))
 ///   .knn(Knn::query_vector_builder(
 ///     "test3",
@@ -68,9 +69,26 @@ pub struct Knn {
   _name: Option<String>,
 }
 
+/// Alias of [`Knn`] that distinguishes the top-level `knn` search body
+/// element from the [`KnnQuery`] clause
+pub type KnnSearch = Knn;
+
 impl Knn {
   add_boost_and_name!();
 
+  /// Creates an instance of [`Knn`] search returning the `k` nearest
+  /// neighbors of `query_vector`
+  ///
+  /// - `field` - The name of the vector field to search against
+  /// - `query_vector` - Query vector. Must have the same number of dimensions
+  ///   as the vector field you are searching against.
+  /// - `k` - Number of nearest neighbors to return as top hits
+  pub fn new<T>(field: T, query_vector: Vec<f32>, k: u32) -> Self
+  where
+    T: ToString, {
+    Self::query_vector(field, query_vector).k(k)
+  }
+
   /// Creates an instance of [`Knn`] search with query vector
   ///
   /// - `field` - The name of the vector field to search against. Must be a
@@ -295,4 +313,36 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_single_clause_as_array() {
+    assert_serialize(
+      Search::new().knn(KnnSearch::new("embedding", vec![0.5, 0.25], 10)),
+      json!({
+          "knn": [
+              { "field": "embedding", "query_vector": [0.5, 0.25], "k": 10 }
+          ]
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_multiple_clauses_with_filter() {
+    assert_serialize(
+      Search::new()
+        .knn(KnnSearch::new("title_vector", vec![0.5, 0.25], 5).filter(Query::term("genre", "drama")))
+        .knn(KnnSearch::new("plot_vector", vec![0.1, 0.9], 10)),
+      json!({
+          "knn": [
+              {
+                  "field": "title_vector",
+                  "query_vector": [0.5, 0.25],
+                  "k": 5,
+                  "filter": { "term": { "genre": { "value": "drama" } } }
+              },
+              { "field": "plot_vector", "query_vector": [0.1, 0.9], "k": 10 }
+          ]
+      }),
+    );
+  }
 }
@@ -15,6 +15,8 @@ mod response;
 // Public modules
 pub mod aggregations;
 pub mod collapse;
+pub mod docvalue_fields;
+pub mod fields;
 pub mod highlight;
 pub mod knn;
 pub mod params;
@@ -30,6 +32,8 @@ pub mod suggesters;
 pub use self::{
   aggregations::*,
   collapse::*,
+  docvalue_fields::*,
+  fields::*,
   highlight::*,
   knn::*,
   params::*,
@@ -1,7 +1,29 @@
-use serde::Serialize;
+use std::str::FromStr;
+
+use serde::{ser::SerializeSeq, Deserializer, Serialize, Serializer};
 
 /// Represents a point in two dimensional space
-#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Default)]
+///
+/// OpenSearch accepts geo points in several forms, and they don't agree on the
+/// order of the coordinates:
+///
+/// | Form | Example | Order |
+/// |------|---------|-------|
+/// | Object | `{ "lat": 41.12, "lon": -71.34 }` | named |
+/// | String | `"41.12,-71.34"` | latitude, longitude |
+/// | Array | `[-71.34, 41.12]` | longitude, latitude (GeoJSON) |
+/// | Geohash | `"drm3btev3e86"` | - |
+///
+/// All of them can be parsed with [`FromStr`] or
+/// [`TryFrom<serde_json::Value>`](TryFrom), and deserialization accepts any
+/// of them as well. [`GeoLocation::new`] takes the latitude first, while the
+/// array and tuple [`From`] conversions follow the GeoJSON order and take the
+/// longitude first.
+///
+/// Locations always serialize to the unambiguous object form, except within
+/// a [`GeoShape`](crate::GeoShape) where GeoJSON requires
+/// `[longitude, latitude]` positions.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct GeoLocation {
   latitude: f32,
   longitude: f32,
@@ -12,13 +34,149 @@ impl GeoLocation {
   pub fn new(latitude: f32, longitude: f32) -> Self {
     Self { latitude, longitude }
   }
+
+  /// Latitude of the location
+  pub fn latitude(&self) -> f32 {
+    self.latitude
+  }
+
+  /// Longitude of the location
+  pub fn longitude(&self) -> f32 {
+    self.longitude
+  }
+
+  /// Decodes a geohash to the center of the cell it identifies
+  pub fn from_geohash(geohash: &str) -> Result<Self, GeoLocationParseError> {
+    const BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+    if geohash.is_empty() {
+      return Err(GeoLocationParseError::InvalidGeohash);
+    }
+
+    let mut latitude = (-90.0_f64, 90.0_f64);
+    let mut longitude = (-180.0_f64, 180.0_f64);
+    let mut is_longitude = true;
+
+    for c in geohash.bytes() {
+      let index = BASE32
+        .iter()
+        .position(|b| *b == c.to_ascii_lowercase())
+        .ok_or(GeoLocationParseError::InvalidGeohash)?;
+
+      for bit in (0..5).rev() {
+        let range = if is_longitude { &mut longitude } else { &mut latitude };
+        let middle = (range.0 + range.1) / 2.0;
+
+        if index & (1 << bit) != 0 {
+          range.0 = middle;
+        } else {
+          range.1 = middle;
+        }
+
+        is_longitude = !is_longitude;
+      }
+    }
+
+    Ok(Self::new(
+      ((latitude.0 + latitude.1) / 2.0) as f32,
+      ((longitude.0 + longitude.1) / 2.0) as f32,
+    ))
+  }
+}
+
+/// Error returned when a [`GeoLocation`] can't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoLocationParseError {
+  /// A `"lat,lon"` string didn't contain two numbers
+  InvalidLatLon,
+
+  /// A string without a comma wasn't a valid geohash
+  InvalidGeohash,
+
+  /// Value was neither a string, an array of two numbers, an object with
+  /// `lat` and `lon` numbers or a GeoJSON point
+  UnsupportedFormat,
+}
+
+impl std::fmt::Display for GeoLocationParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidLatLon => "expected a \"lat,lon\" string".fmt(f),
+      Self::InvalidGeohash => "invalid geohash".fmt(f),
+      Self::UnsupportedFormat => "unsupported geo point format".fmt(f),
+    }
+  }
+}
+
+impl std::error::Error for GeoLocationParseError {}
+
+impl FromStr for GeoLocation {
+  type Err = GeoLocationParseError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.split_once(',') {
+      Some((latitude, longitude)) => {
+        let latitude = latitude
+          .trim()
+          .parse()
+          .map_err(|_| GeoLocationParseError::InvalidLatLon)?;
+        let longitude = longitude
+          .trim()
+          .parse()
+          .map_err(|_| GeoLocationParseError::InvalidLatLon)?;
+        Ok(Self::new(latitude, longitude))
+      }
+      None => Self::from_geohash(s.trim()),
+    }
+  }
+}
+
+impl TryFrom<serde_json::Value> for GeoLocation {
+  type Error = GeoLocationParseError;
+
+  fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+    use serde_json::Value;
+
+    let number = |value: Option<&Value>| {
+      value
+        .and_then(Value::as_f64)
+        .map(|n| n as f32)
+        .ok_or(GeoLocationParseError::UnsupportedFormat)
+    };
+
+    match value {
+      Value::String(s) => s.parse(),
+      Value::Array(array) if array.len() == 2 => Ok(Self::new(number(array.get(1))?, number(array.first())?)),
+      Value::Object(object) => {
+        match object.get("coordinates") {
+          Some(coordinates) => Self::try_from(coordinates.clone()),
+          None => Ok(Self::new(number(object.get("lat"))?, number(object.get("lon"))?)),
+        }
+      }
+      _ => Err(GeoLocationParseError::UnsupportedFormat),
+    }
+  }
 }
 
 impl Serialize for GeoLocation {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where
-    S: serde::Serializer, {
-    [self.longitude, self.latitude].serialize(serializer)
+    S: Serializer, {
+    use serde::ser::SerializeStruct;
+
+    let mut state = serializer.serialize_struct("GeoLocation", 2)?;
+    state.serialize_field("lat", &self.latitude)?;
+    state.serialize_field("lon", &self.longitude)?;
+    state.end()
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoLocation {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>, {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Self::try_from(value).map_err(serde::de::Error::custom)
   }
 }
 
@@ -49,6 +207,67 @@ impl IntoIterator for GeoLocation {
   }
 }
 
+/// Coordinates serialized as GeoJSON `[longitude, latitude]` positions
+pub(crate) trait GeoJsonCoordinates {
+  fn serialize_coordinates<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer;
+}
+
+struct GeoJsonPositions<'a, T: ?Sized>(&'a T);
+
+impl<T> Serialize for GeoJsonPositions<'_, T>
+where
+  T: GeoJsonCoordinates + ?Sized,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    self.0.serialize_coordinates(serializer)
+  }
+}
+
+impl GeoJsonCoordinates for GeoLocation {
+  fn serialize_coordinates<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    [self.longitude, self.latitude].serialize(serializer)
+  }
+}
+
+impl<T> GeoJsonCoordinates for Vec<T>
+where
+  T: GeoJsonCoordinates,
+{
+  fn serialize_coordinates<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    serializer.collect_seq(self.iter().map(GeoJsonPositions))
+  }
+}
+
+impl<T> GeoJsonCoordinates for (T, T)
+where
+  T: GeoJsonCoordinates,
+{
+  fn serialize_coordinates<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    let mut seq = serializer.serialize_seq(Some(2))?;
+    seq.serialize_element(&GeoJsonPositions(&self.0))?;
+    seq.serialize_element(&GeoJsonPositions(&self.1))?;
+    seq.end()
+  }
+}
+
+/// `serialize_with` adapter for GeoJSON `coordinates` fields
+pub(crate) fn serialize_coordinates<T, S>(coordinates: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+  T: GeoJsonCoordinates,
+  S: Serializer, {
+  coordinates.serialize_coordinates(serializer)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -56,8 +275,62 @@ mod tests {
 
   #[test]
   fn serialization() {
-    assert_serialize(GeoLocation::new(1.1, 2.2), json!([2.2, 1.1]));
-    assert_serialize(GeoLocation::from([2.2, 1.1]), json!([2.2, 1.1]));
-    assert_serialize(GeoLocation::from((2.2, 1.1)), json!([2.2, 1.1]));
+    assert_serialize(GeoLocation::new(1.1, 2.2), json!({ "lat": 1.1, "lon": 2.2 }));
+    assert_serialize(GeoLocation::from([2.2, 1.1]), json!({ "lat": 1.1, "lon": 2.2 }));
+    assert_serialize(GeoLocation::from((2.2, 1.1)), json!({ "lat": 1.1, "lon": 2.2 }));
+  }
+
+  #[test]
+  fn parses_all_input_forms() {
+    let expected = GeoLocation::new(41.12, -71.34);
+
+    assert_eq!("41.12,-71.34".parse::<GeoLocation>(), Ok(expected));
+    assert_eq!(GeoLocation::try_from(json!("41.12, -71.34")), Ok(expected));
+    assert_eq!(GeoLocation::try_from(json!([-71.34, 41.12])), Ok(expected));
+    assert_eq!(
+      GeoLocation::try_from(json!({ "lat": 41.12, "lon": -71.34 })),
+      Ok(expected)
+    );
+    assert_eq!(
+      GeoLocation::try_from(json!({ "type": "Point", "coordinates": [-71.34, 41.12] })),
+      Ok(expected)
+    );
+
+    let geohash: GeoLocation = "drm3btev3e86".parse().unwrap();
+    assert!((geohash.latitude() - expected.latitude()).abs() < 1e-4);
+    assert!((geohash.longitude() - expected.longitude()).abs() < 1e-4);
+  }
+
+  #[test]
+  fn deserializes_all_input_forms() {
+    let expected = GeoLocation::new(41.12, -71.34);
+
+    for value in [
+      json!("41.12,-71.34"),
+      json!([-71.34, 41.12]),
+      json!({ "lat": 41.12, "lon": -71.34 }),
+    ] {
+      assert_eq!(serde_json::from_value::<GeoLocation>(value).unwrap(), expected);
+    }
+
+    let geohash: GeoLocation = serde_json::from_value(json!("drm3btev3e86")).unwrap();
+    assert!((geohash.latitude() - expected.latitude()).abs() < 1e-4);
+    assert!((geohash.longitude() - expected.longitude()).abs() < 1e-4);
+  }
+
+  #[test]
+  fn rejects_invalid_input() {
+    assert_eq!(
+      "41.12,abc".parse::<GeoLocation>(),
+      Err(GeoLocationParseError::InvalidLatLon)
+    );
+    assert_eq!(
+      "dra!".parse::<GeoLocation>(),
+      Err(GeoLocationParseError::InvalidGeohash)
+    );
+    assert_eq!(
+      GeoLocation::try_from(json!([1.0, 2.0, 3.0])),
+      Err(GeoLocationParseError::UnsupportedFormat)
+    );
   }
 }
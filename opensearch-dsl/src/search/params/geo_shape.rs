@@ -13,6 +13,7 @@ pub enum GeoShape {
   #[serde(rename = "point")]
   Point {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: GeoLocation,
   },
 
@@ -20,6 +21,7 @@ pub enum GeoShape {
   #[serde(rename = "linestring")]
   LineString {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: Vec<GeoLocation>,
   },
 
@@ -29,6 +31,7 @@ pub enum GeoShape {
   #[serde(rename = "polygon")]
   Polygon {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: Vec<Vec<GeoLocation>>,
   },
 
@@ -36,6 +39,7 @@ pub enum GeoShape {
   #[serde(rename = "multipoint")]
   MultiPoint {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: Vec<GeoLocation>,
   },
 
@@ -43,6 +47,7 @@ pub enum GeoShape {
   #[serde(rename = "multilinestring")]
   MultiLineString {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: Vec<Vec<GeoLocation>>,
   },
 
@@ -50,6 +55,7 @@ pub enum GeoShape {
   #[serde(rename = "multipolygon")]
   MultiPolygon {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: Vec<Vec<Vec<GeoLocation>>>,
   },
 
@@ -58,6 +64,7 @@ pub enum GeoShape {
   #[serde(rename = "envelope")]
   Envelope {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: (GeoLocation, GeoLocation),
   },
 
@@ -66,6 +73,7 @@ pub enum GeoShape {
   #[serde(rename = "circle")]
   Circle {
     /// Coordinates
+    #[serde(serialize_with = "serialize_coordinates")]
     coordinates: GeoLocation,
 
     /// Circle radius
@@ -336,7 +344,7 @@ mod tests {
     );
 
     assert_serialize(
-      GeoShape::circle([-45.0, 45.0], Distance::Meters(100)),
+      GeoShape::circle([-45.0, 45.0], Distance::Meters(100.0)),
       json!({
           "type": "circle",
           "radius": "100m",
@@ -0,0 +1,140 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The minimum number or percentage of optional clauses that must match for a
+/// document to be returned.
+///
+/// Serializes to the textual form OpenSearch expects, e.g. `"2"`, `"-25%"` or
+/// `"3<90%"`.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/minimum-should-match/>
+#[derive(Debug, Clone, PartialEq)]
+pub enum MinimumShouldMatch {
+  /// A fixed number of clauses, or when negative, the number of clauses
+  /// that may be missing
+  Count(i32),
+
+  /// A percentage of the total number of clauses, or when negative, the
+  /// percentage of clauses that may be missing
+  Percentage(f32),
+
+  /// A conditional specification such as `3<90%` or `2<-25% 9<-3`
+  Combination(String),
+}
+
+impl fmt::Display for MinimumShouldMatch {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Count(count) => count.fmt(f),
+      Self::Percentage(percentage) => write!(f, "{percentage}%"),
+      Self::Combination(combination) => combination.fmt(f),
+    }
+  }
+}
+
+impl From<i32> for MinimumShouldMatch {
+  fn from(value: i32) -> Self {
+    Self::Count(value)
+  }
+}
+
+impl From<f32> for MinimumShouldMatch {
+  fn from(value: f32) -> Self {
+    Self::Percentage(value)
+  }
+}
+
+impl From<&str> for MinimumShouldMatch {
+  fn from(value: &str) -> Self {
+    let trimmed = value.trim();
+
+    if let Ok(count) = trimmed.parse() {
+      return Self::Count(count);
+    }
+
+    if let Some(percentage) = trimmed.strip_suffix('%').and_then(|p| p.parse().ok()) {
+      return Self::Percentage(percentage);
+    }
+
+    Self::Combination(value.to_string())
+  }
+}
+
+impl From<String> for MinimumShouldMatch {
+  fn from(value: String) -> Self {
+    Self::from(value.as_str())
+  }
+}
+
+impl Serialize for MinimumShouldMatch {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for MinimumShouldMatch {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>, {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+      Count(i32),
+      Text(String),
+    }
+
+    Ok(match Raw::deserialize(deserializer)? {
+      Raw::Count(count) => Self::Count(count),
+      Raw::Text(text) => Self::from(text),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::util::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize(
+      [
+        MinimumShouldMatch::Count(2),
+        MinimumShouldMatch::Count(-1),
+        MinimumShouldMatch::Percentage(75.0),
+        MinimumShouldMatch::Percentage(-25.5),
+        MinimumShouldMatch::Combination("3<90%".to_string()),
+      ],
+      json!(["2", "-1", "75%", "-25.5%", "3<90%"]),
+    )
+  }
+
+  #[test]
+  fn conversion() {
+    assert_eq!(MinimumShouldMatch::from(3), MinimumShouldMatch::Count(3));
+    assert_eq!(MinimumShouldMatch::from("-2"), MinimumShouldMatch::Count(-2));
+    assert_eq!(MinimumShouldMatch::from("75%"), MinimumShouldMatch::Percentage(75.0));
+    assert_eq!(
+      MinimumShouldMatch::from("2<-25% 9<-3"),
+      MinimumShouldMatch::Combination("2<-25% 9<-3".to_string())
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let values: Vec<MinimumShouldMatch> = serde_json::from_value(json!([2, "-1", "30%", "3<90%"])).unwrap();
+
+    assert_eq!(
+      values,
+      [
+        MinimumShouldMatch::Count(2),
+        MinimumShouldMatch::Count(-1),
+        MinimumShouldMatch::Percentage(30.0),
+        MinimumShouldMatch::Combination("3<90%".to_string()),
+      ]
+    );
+  }
+}
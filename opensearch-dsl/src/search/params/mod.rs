@@ -5,6 +5,7 @@ mod date;
 mod geo_distance_type;
 mod geo_location;
 mod geo_shape;
+mod minimum_should_match;
 mod number;
 mod point_in_time;
 mod score_mode;
@@ -18,7 +19,7 @@ mod track_total_hits;
 mod units;
 
 pub use self::{
-  coordinate::*, date::*, geo_distance_type::*, geo_location::*, geo_shape::*, number::*, point_in_time::*,
-  score_mode::*, script_sort_type::*, search_filter::*, shape::*, term::*, terms::*, text::*, track_total_hits::*,
-  units::*,
+  coordinate::*, date::*, geo_distance_type::*, geo_location::*, geo_shape::*, minimum_should_match::*, number::*,
+  point_in_time::*, score_mode::*, script_sort_type::*, search_filter::*, shape::*, term::*, terms::*, text::*,
+  track_total_hits::*, units::*,
 };
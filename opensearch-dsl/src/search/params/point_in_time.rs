@@ -1,11 +1,16 @@
 use super::Time;
+use crate::util::*;
 
 /// A point in time (PIT) is a point that represents a consistent view of the
 /// data at that time.
+///
+/// <https://opensearch.org/docs/latest/search-plugins/point-in-time/>
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct PointInTime {
   id: String,
-  keep_alive: Time,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  keep_alive: Option<Time>,
 }
 
 impl PointInTime {
@@ -13,16 +18,23 @@ impl PointInTime {
   ///
   /// - The `id` parameter tells OpenSearch to execute the request using
   ///   contexts from this point in time.
-  /// - The `keep_alive` parameter tells OpenSearch how long it should extend
-  ///   the time to live of the point in time.
-  pub fn new<T>(id: T, keep_alive: Time) -> Self
+  pub fn new<T>(id: T) -> Self
   where
     T: ToString, {
     Self {
       id: id.to_string(),
-      keep_alive,
+      keep_alive: None,
     }
   }
+
+  /// The `keep_alive` parameter tells OpenSearch how long it should extend
+  /// the time to live of the point in time.
+  pub fn keep_alive<T>(mut self, keep_alive: T) -> Self
+  where
+    T: Into<Time>, {
+    self.keep_alive = Some(keep_alive.into());
+    self
+  }
 }
 
 #[cfg(test)]
@@ -33,7 +45,7 @@ mod tests {
   #[test]
   fn adds_boolean() {
     assert_serialize(
-            Search::new().pit(PointInTime::new("46ToAwMDaWR5BXV1aWQyKwZub2RlXzMAAAAAAAAAACoBYwADaWR4BXV1aWQxAgZub2RlXzEAAAAAAAAAAAEBYQADaWR5BXV1aWQyKgZub2RlXzIAAAAAAAAAAAwBYgACBXV1aWQyAAAFdXVpZDEAAQltYXRjaF9hbGw_gAAAAA", Time::Minutes(1))),
+            Search::new().pit(PointInTime::new("46ToAwMDaWR5BXV1aWQyKwZub2RlXzMAAAAAAAAAACoBYwADaWR4BXV1aWQxAgZub2RlXzEAAAAAAAAAAAEBYQADaWR5BXV1aWQyKgZub2RlXzIAAAAAAAAAAAwBYgACBXV1aWQyAAAFdXVpZDEAAQltYXRjaF9hbGw_gAAAAA").keep_alive(Time::Minutes(1))),
             json!({
                 "pit": {
                     "id": "46ToAwMDaWR5BXV1aWQyKwZub2RlXzMAAAAAAAAAACoBYwADaWR4BXV1aWQxAgZub2RlXzEAAAAAAAAAAAEBYQADaWR5BXV1aWQyKgZub2RlXzIAAAAAAAAAAAwBYgACBXV1aWQyAAAFdXVpZDEAAQltYXRjaF9hbGw_gAAAAA",
@@ -42,4 +54,16 @@ mod tests {
             }),
         );
   }
+
+  #[test]
+  fn skips_keep_alive_when_not_set() {
+    assert_serialize(
+      Search::new().pit(PointInTime::new("46ToAwMDaWR5BXV1aWQyKwZub2RlXzMA")),
+      json!({
+          "pit": {
+              "id": "46ToAwMDaWR5BXV1aWQyKwZub2RlXzMA"
+          }
+      }),
+    );
+  }
 }
@@ -32,6 +32,31 @@ pub enum SourceFilter {
   },
 }
 
+impl SourceFilter {
+  /// Creates a [`SourceFilter`] returning only the parts of `_source` matching
+  /// the given wildcard patterns
+  pub fn includes<T>(includes: T) -> Self
+  where
+    T: IntoIterator,
+    T::Item: ToString, {
+    SourceFilter::Includes(includes.into_iter().map(|include| include.to_string()).collect())
+  }
+
+  /// Creates a [`SourceFilter`] returning the parts of `_source` matching
+  /// `includes`, minus the ones matching `excludes`
+  pub fn includes_excludes<T, U>(includes: T, excludes: U) -> Self
+  where
+    T: IntoIterator,
+    T::Item: ToString,
+    U: IntoIterator,
+    U::Item: ToString, {
+    SourceFilter::IncludesExcludes {
+      includes: includes.into_iter().map(|include| include.to_string()).collect(),
+      excludes: excludes.into_iter().map(|exclude| exclude.to_string()).collect(),
+    }
+  }
+}
+
 // -- Boolean
 
 impl From<bool> for SourceFilter {
@@ -153,6 +178,7 @@ impl<const M: usize, const N: usize> From<([Cow<'_, str>; M], [Cow<'_, str>; N])
 
 #[cfg(test)]
 mod tests {
+  use super::*;
   use crate::{util::assert_serialize, Search};
 
   #[test]
@@ -187,4 +213,27 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn adds_includes_from_constructor() {
+    assert_serialize(
+      Search::new().source(SourceFilter::includes(["a", "b.*"])),
+      json!({
+          "_source": ["a", "b.*"]
+      }),
+    );
+  }
+
+  #[test]
+  fn adds_includes_excludes_from_constructor() {
+    assert_serialize(
+      Search::new().source(SourceFilter::includes_excludes(["obj.*"], ["obj.secret"])),
+      json!({
+          "_source": {
+              "includes": ["obj.*"],
+              "excludes": ["obj.secret"]
+          }
+      }),
+    );
+  }
 }
@@ -272,23 +272,40 @@ impl<'de> Deserialize<'de> for Size {
 /// such as `"1km"` or `"2mi"` (2 miles).
 ///
 /// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-geo-distance-query.html>
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[allow(missing_docs)]
 pub enum Distance {
-  Miles(u64),
-  Yards(u64),
-  Feet(u64),
-  Inches(u64),
-  Kilometers(u64),
-  Meters(u64),
-  Centimeter(u64),
-  Millimeters(u64),
-  NauticalMiles(u64),
+  Miles(f64),
+  Yards(f64),
+  Feet(f64),
+  Inches(f64),
+  Kilometers(f64),
+  Meters(f64),
+  Centimeter(f64),
+  Millimeters(f64),
+  NauticalMiles(f64),
 }
 
 impl Default for Distance {
   fn default() -> Self {
-    Self::Meters(100)
+    Self::Meters(100.0)
+  }
+}
+
+impl Distance {
+  /// Creates a [`Distance`] of `value` expressed in `unit`
+  pub fn new(value: f64, unit: DistanceUnit) -> Self {
+    match unit {
+      DistanceUnit::Miles => Self::Miles(value),
+      DistanceUnit::Yards => Self::Yards(value),
+      DistanceUnit::Feet => Self::Feet(value),
+      DistanceUnit::Inches => Self::Inches(value),
+      DistanceUnit::Kilometers => Self::Kilometers(value),
+      DistanceUnit::Meters => Self::Meters(value),
+      DistanceUnit::Centimeter => Self::Centimeter(value),
+      DistanceUnit::Millimeters => Self::Millimeters(value),
+      DistanceUnit::NauticalMiles => Self::NauticalMiles(value),
+    }
   }
 }
 
@@ -327,13 +344,15 @@ impl<'de> Deserialize<'de> for Distance {
       fn visit_str<E>(self, value: &str) -> Result<Distance, E>
       where
         E: de::Error, {
-        let len = value.len();
-        let number = &value[..len - 2];
-        let unit = &value[len - 2..];
+        let split = value
+          .find(|c: char| !c.is_ascii_digit() && c != '.')
+          .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
 
-        let number = number.parse::<u64>().map_err(E::custom)?;
+        let number = number.parse::<f64>().map_err(E::custom)?;
 
         match unit {
+          "" => Ok(Distance::Meters(number)),
           "mi" => Ok(Distance::Miles(number)),
           "yd" => Ok(Distance::Yards(number)),
           "ft" => Ok(Distance::Feet(number)),
@@ -0,0 +1,201 @@
+use crate::search::*;
+
+/// Structural complexity of a query tree, see [`Query::complexity`]
+///
+/// Useful for rejecting overly expensive user supplied queries before sending
+/// them to the cluster.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct QueryComplexity {
+  /// Number of leaf clauses, i.e. clauses that don't wrap other queries
+  pub leaf_clauses: usize,
+
+  /// Nesting depth of the deepest clause, a single leaf query has a depth of
+  /// `1`
+  pub max_depth: usize,
+
+  /// Number of clauses OpenSearch rejects when
+  /// `search.allow_expensive_queries` is disabled: `fuzzy`, `prefix`,
+  /// `regexp`, `wildcard`, `script`, `script_score`, `percolate` and the
+  /// joining queries
+  pub expensive_clauses: usize,
+}
+
+impl Query {
+  /// Walks the query tree, including all compound and span clauses, and
+  /// returns its [`QueryComplexity`]
+  pub fn complexity(&self) -> QueryComplexity {
+    let mut visitor = ComplexityVisitor::default();
+    self.visit(&mut visitor);
+    visitor.complexity
+  }
+}
+
+#[derive(Default)]
+struct ComplexityVisitor {
+  depth: usize,
+  complexity: QueryComplexity,
+}
+
+impl ComplexityVisitor {
+  fn enter(&mut self) {
+    self.depth += 1;
+    self.complexity.max_depth = self.complexity.max_depth.max(self.depth);
+  }
+}
+
+impl QueryVisitor for ComplexityVisitor {
+  fn visit_query(&mut self, query: &Query) {
+    self.enter();
+
+    if matches!(
+      query,
+      Query::Fuzzy(_)
+        | Query::Prefix(_)
+        | Query::Regexp(_)
+        | Query::Wildcard(_)
+        | Query::Script(_)
+        | Query::ScriptScore(_)
+        | Query::Percolate(_)
+        | Query::PercolateLookup(_)
+        | Query::HasChild(_)
+        | Query::HasParent(_)
+        | Query::ParentId(_)
+    ) {
+      self.complexity.expensive_clauses += 1;
+    }
+
+    if !matches!(
+      query,
+      Query::Bool(_)
+        | Query::Boosting(_)
+        | Query::ConstantScore(_)
+        | Query::DisMax(_)
+        | Query::FunctionScore(_)
+        | Query::Nested(_)
+        | Query::HasChild(_)
+        | Query::HasParent(_)
+        | Query::Pinned(_)
+        | Query::ScriptScore(_)
+        | Query::SpanContaining(_)
+        | Query::SpanFieldMasking(_)
+        | Query::SpanFirst(_)
+        | Query::SpanMulti(_)
+        | Query::SpanNear(_)
+        | Query::SpanNot(_)
+        | Query::SpanOr(_)
+        | Query::SpanWithin(_)
+    ) {
+      self.complexity.leaf_clauses += 1;
+    }
+  }
+
+  fn leave_query(&mut self, _query: &Query) {
+    self.depth -= 1;
+  }
+
+  fn visit_span_query(&mut self, query: &SpanQuery) {
+    self.enter();
+
+    if let SpanQuery::SpanTerm(_) = query {
+      self.complexity.leaf_clauses += 1;
+    }
+  }
+
+  fn leave_span_query(&mut self, _query: &SpanQuery) {
+    self.depth -= 1;
+  }
+
+  fn visit_multi_term_query(&mut self, query: &MultiTermQuery) {
+    self.enter();
+    self.depth -= 1;
+    self.complexity.leaf_clauses += 1;
+
+    if !matches!(query, MultiTermQuery::Range(_)) {
+      self.complexity.expensive_clauses += 1;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_single_leaf() {
+    assert_eq!(
+      Query::from(Query::term("user", "kimchy")).complexity(),
+      QueryComplexity {
+        leaf_clauses: 1,
+        max_depth: 1,
+        expensive_clauses: 0,
+      }
+    );
+  }
+
+  #[test]
+  fn counts_nested_bool_with_regexp_leaf() {
+    let query = Query::from(
+      Query::bool()
+        .must(Query::term("user", "kimchy"))
+        .filter(
+          Query::bool()
+            .should(Query::regexp("name", "k.*y"))
+            .should(Query::constant_score(Query::wildcard("tag", "el*"))),
+        )
+        .must_not(Query::range("age").gte(10)),
+    );
+
+    assert_eq!(
+      query.complexity(),
+      QueryComplexity {
+        leaf_clauses: 4,
+        max_depth: 4,
+        expensive_clauses: 2,
+      }
+    );
+  }
+
+  #[test]
+  fn scores_expensive_clauses_under_pinned_and_function_score() {
+    let query = Query::from(
+      Query::function_score()
+        .query(Query::pinned(
+          PinnedQueryValues::ids([1]),
+          Query::bool()
+            .should(Query::regexp("name", "k.*y"))
+            .should(Query::wildcard("tag", "el*")),
+        ))
+        .function(Weight::new(2.0).filter(Query::script(Script::source("doc['likes'].value > 10")))),
+    );
+
+    assert_eq!(
+      query.complexity(),
+      QueryComplexity {
+        leaf_clauses: 3,
+        max_depth: 4,
+        expensive_clauses: 3,
+      }
+    );
+  }
+
+  #[test]
+  fn walks_span_and_joining_clauses() {
+    let query = Query::from(
+      Query::dis_max()
+        .query(Query::nested(
+          "comments",
+          Query::span_multi(Query::prefix("comments.text", "wor")),
+        ))
+        .query(Query::has_child("answer", Query::term("votes", 10))),
+    );
+
+    assert_eq!(
+      query.complexity(),
+      QueryComplexity {
+        leaf_clauses: 2,
+        max_depth: 4,
+        expensive_clauses: 2,
+      }
+    );
+  }
+}
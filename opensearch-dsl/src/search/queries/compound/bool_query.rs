@@ -34,25 +34,39 @@ use crate::{search::*, util::*};
 #[serde(remote = "Self")]
 pub struct BoolQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  must: QueryCollection,
+  pub(crate) must: QueryCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  filter: QueryCollection,
+  pub(crate) filter: QueryCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  should: QueryCollection,
+  pub(crate) should: QueryCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  must_not: QueryCollection,
+  pub(crate) must_not: QueryCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   _name: Option<String>,
+
+  #[serde(skip)]
+  dedupe: Dedupe,
+}
+
+/// Whether clauses added to a [`BoolQuery`] are deduplicated. Always compares
+/// equal, as it isn't serialized and only affects clauses added later.
+#[derive(Debug, Default, Clone, Copy)]
+struct Dedupe(bool);
+
+impl PartialEq for Dedupe {
+  fn eq(&self, _: &Self) -> bool {
+    true
+  }
 }
 
 impl Query {
@@ -72,6 +86,9 @@ impl BoolQuery {
     T: IntoIterator,
     T::Item: Into<Query>, {
     self.must.extend(query);
+    if self.dedupe.0 {
+      self.must.dedupe();
+    }
     self
   }
 
@@ -81,6 +98,9 @@ impl BoolQuery {
     T: IntoIterator,
     T::Item: Into<Query>, {
     self.should.extend(query);
+    if self.dedupe.0 {
+      self.should.dedupe();
+    }
     self
   }
 
@@ -93,6 +113,9 @@ impl BoolQuery {
     T: IntoIterator,
     T::Item: Into<Query>, {
     self.filter.extend(query);
+    if self.dedupe.0 {
+      self.filter.dedupe();
+    }
     self
   }
 
@@ -105,6 +128,9 @@ impl BoolQuery {
     T: IntoIterator,
     T::Item: Into<Query>, {
     self.must_not.extend(query);
+    if self.dedupe.0 {
+      self.must_not.dedupe();
+    }
     self
   }
 
@@ -119,8 +145,44 @@ impl BoolQuery {
   /// [minimum_should_match parameter](https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-minimum-should-match.html).
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
+    self
+  }
+
+  /// Clauses added with [`must`](Self::must)
+  pub fn must_clauses(&self) -> &[Query] {
+    self.must.as_slice()
+  }
+
+  /// Clauses added with [`should`](Self::should)
+  pub fn should_clauses(&self) -> &[Query] {
+    self.should.as_slice()
+  }
+
+  /// Clauses added with [`filter`](Self::filter)
+  pub fn filter_clauses(&self) -> &[Query] {
+    self.filter.as_slice()
+  }
+
+  /// Clauses added with [`must_not`](Self::must_not)
+  pub fn must_not_clauses(&self) -> &[Query] {
+    self.must_not.as_slice()
+  }
+
+  /// Removes structurally equal clauses within each of `must`, `should`,
+  /// `filter` and `must_not`, both for the clauses added so far and for
+  /// the ones added afterwards. Only the first occurrence of a clause is
+  /// kept.
+  ///
+  /// Useful when building queries programmatically from sources that may
+  /// repeat the same condition.
+  pub fn dedupe(mut self) -> Self {
+    self.dedupe = Dedupe(true);
+    self.must.dedupe();
+    self.should.dedupe();
+    self.filter.dedupe();
+    self.must_not.dedupe();
     self
   }
 }
@@ -214,4 +276,80 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn reads_back_clauses() {
+    let query = Query::bool()
+      .must(Query::term("title", "godfather"))
+      .should([Query::term("genre", "crime"), Query::term("genre", "drama")])
+      .filter(Query::range("year").gte(1970))
+      .must_not(Query::term("rating", "R"));
+
+    assert_eq!(query.must_clauses(), [Query::from(Query::term("title", "godfather"))]);
+    assert_eq!(
+      query.should_clauses(),
+      [
+        Query::from(Query::term("genre", "crime")),
+        Query::from(Query::term("genre", "drama"))
+      ]
+    );
+    assert_eq!(query.filter_clauses(), [Query::from(Query::range("year").gte(1970))]);
+    assert_eq!(query.must_not_clauses(), [Query::from(Query::term("rating", "R"))]);
+
+    assert!(Query::bool().must_clauses().is_empty());
+  }
+
+  #[test]
+  fn keeps_duplicate_clauses_by_default() {
+    assert_serialize_query(
+      Query::bool()
+        .filter(Query::term("status", "active"))
+        .filter(Query::term("status", "active")),
+      json!({
+          "bool": {
+              "filter": [
+                  { "term": { "status": { "value": "active" } } },
+                  { "term": { "status": { "value": "active" } } }
+              ]
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn dedupes_equal_clauses() {
+    assert_serialize_query(
+      Query::bool()
+        .filter(Query::term("status", "active"))
+        .must_not([Query::term("deleted", true), Query::term("deleted", true)])
+        .dedupe()
+        .filter(Query::term("status", "active"))
+        .filter(Query::term("region", "eu"))
+        .should(Query::term("status", "active")),
+      json!({
+          "bool": {
+              "filter": [
+                  { "term": { "status": { "value": "active" } } },
+                  { "term": { "region": { "value": "eu" } } }
+              ],
+              "should": [
+                  { "term": { "status": { "value": "active" } } }
+              ],
+              "must_not": [
+                  { "term": { "deleted": { "value": true } } }
+              ]
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn dedupe_flag_is_ignored_by_equality() {
+    let deduped = Query::bool().filter(Query::match_all()).dedupe();
+    let plain = Query::bool().filter(Query::match_all());
+    assert_eq!(deduped, plain);
+
+    let round_tripped: BoolQuery = serde_json::from_value(serde_json::to_value(&deduped).unwrap()).unwrap();
+    assert_eq!(round_tripped, deduped);
+  }
 }
@@ -20,9 +20,9 @@ use crate::{search::*, util::*};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct BoostingQuery {
-  positive: Box<Query>,
+  pub(crate) positive: Box<Query>,
 
-  negative: Box<Query>,
+  pub(crate) negative: Box<Query>,
 
   negative_boost: NegativeBoost,
 
@@ -51,9 +51,10 @@ impl Query {
   ///   decrease the
   /// [relevance scores](https://www.elastic.co/guide/en/opensearch/reference/current/query-filter-context.html#relevance-scores)
   /// of documents matching the `negative` query.
-  pub fn boosting<Q, B>(positive: Q, negative: Q, negative_boost: B) -> BoostingQuery
+  pub fn boosting<P, N, B>(positive: P, negative: N, negative_boost: B) -> BoostingQuery
   where
-    Q: Into<Query>,
+    P: Into<Query>,
+    N: Into<Query>,
     B: Into<NegativeBoost>, {
     BoostingQuery {
       positive: Box::new(positive.into()),
@@ -67,6 +68,21 @@ impl Query {
 
 impl BoostingQuery {
   add_boost_and_name!();
+
+  /// Query returned documents must match
+  pub fn positive(&self) -> &Query {
+    &self.positive
+  }
+
+  /// Query demoting the matching documents
+  pub fn negative(&self) -> &Query {
+    &self.negative
+  }
+
+  /// Factor applied to the score of documents matching the `negative` query
+  pub fn negative_boost(&self) -> NegativeBoost {
+    self.negative_boost
+  }
 }
 
 impl ShouldSkip for BoostingQuery {
@@ -134,4 +150,25 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn combines_different_query_types() {
+    let query = Query::boosting(
+      Query::term("text", "apple"),
+      Query::r#match("text", "pie tart fruit crumble tree"),
+      0.5,
+    );
+
+    assert_eq!(query.negative_boost(), 0.5);
+    assert_serialize_query(
+      query,
+      json!({
+          "boosting": {
+              "positive": { "term": { "text": { "value": "apple" } } },
+              "negative": { "match": { "text": { "query": "pie tart fruit crumble tree" } } },
+              "negative_boost": 0.5
+          }
+      }),
+    );
+  }
 }
@@ -18,7 +18,7 @@ use crate::{search::*, util::*};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct ConstantScoreQuery {
-  filter: Box<Query>,
+  pub(crate) filter: Box<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
@@ -49,6 +49,17 @@ impl Query {
 
 impl ConstantScoreQuery {
   add_boost_and_name!();
+
+  /// Filter query whose matches are returned with a constant score
+  pub fn filter(&self) -> &Query {
+    &self.filter
+  }
+}
+
+impl From<Query> for ConstantScoreQuery {
+  fn from(filter: Query) -> Self {
+    Query::constant_score(filter)
+  }
 }
 
 impl ShouldSkip for ConstantScoreQuery {
@@ -98,4 +109,40 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn wraps_query_as_filter() {
+    let filter = Query::from(Query::term("user", "kimchy"));
+    let query = ConstantScoreQuery::from(filter.clone()).boost(1.5);
+
+    assert_eq!(query.filter(), &filter);
+    assert_serialize_query(
+      query,
+      json!({
+          "constant_score": {
+              "filter": {
+                  "term": {
+                      "user": {
+                          "value": "kimchy"
+                      }
+                  }
+              },
+              "boost": 1.5
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::constant_score(Query::exists("user")).boost(1.2);
+    let json = json!({
+        "constant_score": {
+            "filter": { "exists": { "field": "user" } },
+            "boost": 1.2
+        }
+    });
+
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
@@ -27,7 +27,7 @@ use crate::{search::*, util::*};
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct DisMaxQuery {
-  queries: QueryCollection,
+  pub(crate) queries: QueryCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   tie_breaker: Option<f32>,
@@ -84,6 +84,17 @@ impl DisMaxQuery {
     self.tie_breaker = Some(tie_breaker);
     self
   }
+
+  /// Query clauses of the disjunction
+  pub fn queries(&self) -> &[Query] {
+    self.queries.as_slice()
+  }
+}
+
+impl From<Vec<Query>> for DisMaxQuery {
+  fn from(queries: Vec<Query>) -> Self {
+    Query::dis_max().query(queries)
+  }
 }
 
 impl ShouldSkip for DisMaxQuery {
@@ -183,4 +194,23 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn builds_from_vec() {
+    let queries = vec![Query::from(Query::term("t1", "a")), Query::from(Query::term("t2", "b"))];
+    let query = DisMaxQuery::from(queries.clone());
+
+    assert_eq!(query.queries(), queries.as_slice());
+    assert_serialize_query(
+      query,
+      json!({
+          "dis_max": {
+              "queries": [
+                  { "term": { "t1": { "value": "a" } } },
+                  { "term": { "t2": { "value": "b" } } }
+              ]
+          }
+      }),
+    );
+  }
 }
@@ -35,10 +35,10 @@ use crate::{search::*, util::*};
 #[serde(remote = "Self")]
 pub struct FunctionScoreQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  query: Option<Box<Query>>,
+  pub(crate) query: Option<Box<Query>>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  functions: Vec<Function>,
+  pub(crate) functions: Vec<Function>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   max_boost: Option<f32>,
@@ -145,6 +145,8 @@ deserialize_with_root!("function_score": FunctionScoreQuery);
 
 #[cfg(test)]
 mod tests {
+  use chrono::prelude::*;
+
   use super::*;
 
   #[test]
@@ -201,6 +203,57 @@ mod tests {
     );
   }
 
+  #[test]
+  fn serializes_field_value_factor_and_date_decay() {
+    assert_serialize_query(
+      Query::function_score()
+        .function(
+          Function::field_value_factor("likes")
+            .factor(1.2)
+            .modifier(FieldValueFactorModifier::Sqrt)
+            .missing(1.0),
+        )
+        .function(
+          Function::decay(
+            DecayFunction::Gauss,
+            "published_at",
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).single().unwrap(),
+            Time::Days(10),
+          )
+          .offset(Time::Days(2))
+          .decay(0.5),
+        )
+        .score_mode(FunctionScoreMode::Sum)
+        .boost_mode(FunctionBoostMode::Multiply),
+      json!({
+          "function_score": {
+              "functions": [
+                  {
+                      "field_value_factor": {
+                          "field": "likes",
+                          "factor": 1.2,
+                          "modifier": "sqrt",
+                          "missing": 1.0
+                      }
+                  },
+                  {
+                      "gauss": {
+                          "published_at": {
+                              "origin": "2024-01-01T00:00:00Z",
+                              "scale": "10d",
+                              "offset": "2d",
+                              "decay": 0.5
+                          }
+                      }
+                  }
+              ],
+              "score_mode": "sum",
+              "boost_mode": "multiply"
+          }
+      }),
+    );
+  }
+
   #[test]
   fn issue_24() {
     let _ = json!({
@@ -12,6 +12,8 @@ use crate::{search::*, util::*};
 /// # use opensearch_dsl::queries::params::*;
 /// # let query =
 /// Query::combined_fields(["title", "abstract", "body"], "database systems")
+///   .operator(Operator::And)
+///   .zero_terms_query(ZeroTermsQuery::All)
 ///   .boost(2)
 ///   .name("test");
 /// ```
@@ -32,7 +34,7 @@ pub struct CombinedFieldsQuery {
   operator: Option<Operator>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   zero_terms_query: Option<ZeroTermsQuery>,
@@ -97,8 +99,8 @@ impl CombinedFieldsQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -156,5 +158,19 @@ mod tests {
           }
       }),
     );
+
+    assert_serialize_query(
+      Query::combined_fields(["title", "body"], "quick fox")
+        .operator(Operator::And)
+        .zero_terms_query(ZeroTermsQuery::All),
+      json!({
+          "combined_fields": {
+              "query": "quick fox",
+              "fields": ["title", "body"],
+              "operator": "AND",
+              "zero_terms_query": "all",
+          }
+      }),
+    );
   }
 }
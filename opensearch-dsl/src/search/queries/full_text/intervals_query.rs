@@ -0,0 +1,803 @@
+use crate::{search::*, util::*};
+
+/// Returns documents based on the order and proximity of matching terms.
+///
+/// The `intervals` query uses **matching rules**, constructed from a small set
+/// of definitions. These rules are then applied to terms from a specified
+/// `field`.
+///
+/// The definitions produce sequences of minimal intervals that span terms in a
+/// body of text. These intervals can be further combined and filtered by
+/// parent sources.
+///
+/// To create an intervals query:
+/// ```
+/// # use opensearch_dsl::queries::*;
+/// # use opensearch_dsl::queries::params::*;
+/// # let query =
+/// Query::intervals(
+///   "my_text",
+///   IntervalsAllOf::new([
+///     IntervalsRule::from(
+///       IntervalsMatch::new("my favorite food")
+///         .max_gaps(0)
+///         .ordered(true),
+///     ),
+///     IntervalsRule::from(IntervalsAnyOf::new([
+///       IntervalsMatch::new("hot water"),
+///       IntervalsMatch::new("cold porridge"),
+///     ])),
+///   ])
+///   .ordered(true),
+/// )
+/// .boost(2)
+/// .name("test");
+/// ```
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct IntervalsQuery {
+  #[serde(skip)]
+  field: String,
+
+  #[serde(flatten)]
+  rule: IntervalsRule,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
+}
+
+impl Query {
+  /// Creates an instance of [`IntervalsQuery`]
+  ///
+  /// - `field` - Field you wish to search.
+  /// - `rule` - Matching rule applied to the terms of the `<field>`
+  pub fn intervals<T, U>(field: T, rule: U) -> IntervalsQuery
+  where
+    T: ToString,
+    U: Into<IntervalsRule>, {
+    IntervalsQuery {
+      field: field.to_string(),
+      rule: rule.into(),
+      boost: None,
+      _name: None,
+    }
+  }
+}
+
+impl IntervalsQuery {
+  add_boost_and_name!();
+}
+
+impl ShouldSkip for IntervalsQuery {
+  fn should_skip(&self) -> bool {
+    self.field.should_skip() || self.rule.should_skip()
+  }
+}
+
+serialize_with_root_keyed!("intervals": IntervalsQuery);
+deserialize_with_root_keyed!("intervals": IntervalsQuery);
+
+/// A matching rule of an [`IntervalsQuery`]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsRule {
+  /// Matches analyzed text
+  Match(IntervalsMatch),
+
+  /// Matches terms that start with a specified set of characters
+  Prefix(IntervalsPrefix),
+
+  /// Matches terms using a wildcard pattern
+  Wildcard(IntervalsWildcard),
+
+  /// Matches terms that are similar to the provided term, within an edit
+  /// distance defined by [Fuzziness]
+  Fuzzy(IntervalsFuzzy),
+
+  /// Returns matches that span a combination of other rules
+  AllOf(IntervalsAllOf),
+
+  /// Returns intervals produced by any of its sub-rules
+  AnyOf(IntervalsAnyOf),
+}
+
+impl ShouldSkip for IntervalsRule {
+  fn should_skip(&self) -> bool {
+    match self {
+      Self::Match(rule) => rule.query.should_skip(),
+      Self::Prefix(rule) => rule.prefix.should_skip(),
+      Self::Wildcard(rule) => rule.pattern.should_skip(),
+      Self::Fuzzy(rule) => rule.term.should_skip(),
+      Self::AllOf(rule) => rule.intervals.should_skip(),
+      Self::AnyOf(rule) => rule.intervals.should_skip(),
+    }
+  }
+}
+
+/// The `match` rule matches analyzed text.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-match-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsMatch {
+  query: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  max_gaps: Option<i32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  ordered: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  analyzer: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  filter: Option<Box<IntervalsFilter>>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  use_field: Option<String>,
+}
+
+impl IntervalsMatch {
+  /// Creates an instance of [`IntervalsMatch`]
+  ///
+  /// - `query` - Text you wish to find in the provided `<field>`
+  pub fn new<T>(query: T) -> Self
+  where
+    T: ToString, {
+    Self {
+      query: query.to_string(),
+      max_gaps: None,
+      ordered: None,
+      analyzer: None,
+      filter: None,
+      use_field: None,
+    }
+  }
+
+  /// Maximum number of positions between the matching terms. Terms further
+  /// apart than this are not considered matches. Defaults to `-1`.
+  ///
+  /// If unspecified or set to `-1`, there is no width restriction on the
+  /// match. If set to `0`, the terms must appear next to each other.
+  pub fn max_gaps(mut self, max_gaps: i32) -> Self {
+    self.max_gaps = Some(max_gaps);
+    self
+  }
+
+  /// If `true`, matching terms must appear in their specified order.
+  /// Defaults to `false`.
+  pub fn ordered(mut self, ordered: bool) -> Self {
+    self.ordered = Some(ordered);
+    self
+  }
+
+  /// Analyzer used to analyze terms in the `query`. Defaults to the
+  /// top-level `<field>`'s analyzer.
+  pub fn analyzer<T>(mut self, analyzer: T) -> Self
+  where
+    T: ToString, {
+    self.analyzer = Some(analyzer.to_string());
+    self
+  }
+
+  /// An optional interval filter
+  pub fn filter<T>(mut self, filter: T) -> Self
+  where
+    T: Into<IntervalsFilter>, {
+    self.filter = Some(Box::new(filter.into()));
+    self
+  }
+
+  /// If specified, then match intervals from this field rather than the
+  /// top-level `<field>`. Terms are analyzed using the search analyzer from
+  /// this field.
+  pub fn use_field<T>(mut self, use_field: T) -> Self
+  where
+    T: ToString, {
+    self.use_field = Some(use_field.to_string());
+    self
+  }
+}
+
+/// The `prefix` rule matches terms that start with a specified set of
+/// characters.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-prefix-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsPrefix {
+  prefix: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  analyzer: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  use_field: Option<String>,
+}
+
+impl IntervalsPrefix {
+  /// Creates an instance of [`IntervalsPrefix`]
+  ///
+  /// - `prefix` - Beginning characters of terms you wish to find in the
+  ///   top-level `<field>`
+  pub fn new<T>(prefix: T) -> Self
+  where
+    T: ToString, {
+    Self {
+      prefix: prefix.to_string(),
+      analyzer: None,
+      use_field: None,
+    }
+  }
+
+  /// Analyzer used to normalize the `prefix`. Defaults to the top-level
+  /// `<field>`'s analyzer.
+  pub fn analyzer<T>(mut self, analyzer: T) -> Self
+  where
+    T: ToString, {
+    self.analyzer = Some(analyzer.to_string());
+    self
+  }
+
+  /// If specified, then match intervals from this field rather than the
+  /// top-level `<field>`.
+  pub fn use_field<T>(mut self, use_field: T) -> Self
+  where
+    T: ToString, {
+    self.use_field = Some(use_field.to_string());
+    self
+  }
+}
+
+/// The `wildcard` rule matches terms using a wildcard pattern.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-wildcard-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsWildcard {
+  pattern: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  analyzer: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  use_field: Option<String>,
+}
+
+impl IntervalsWildcard {
+  /// Creates an instance of [`IntervalsWildcard`]
+  ///
+  /// - `pattern` - Wildcard pattern used to find matching terms. Supports `?`,
+  ///   which matches any single character, and `*`, which can match zero or
+  ///   more characters.
+  pub fn new<T>(pattern: T) -> Self
+  where
+    T: ToString, {
+    Self {
+      pattern: pattern.to_string(),
+      analyzer: None,
+      use_field: None,
+    }
+  }
+
+  /// Analyzer used to normalize the `pattern`. Defaults to the top-level
+  /// `<field>`'s analyzer.
+  pub fn analyzer<T>(mut self, analyzer: T) -> Self
+  where
+    T: ToString, {
+    self.analyzer = Some(analyzer.to_string());
+    self
+  }
+
+  /// If specified, then match intervals from this field rather than the
+  /// top-level `<field>`.
+  pub fn use_field<T>(mut self, use_field: T) -> Self
+  where
+    T: ToString, {
+    self.use_field = Some(use_field.to_string());
+    self
+  }
+}
+
+/// The `fuzzy` rule matches terms that are similar to the provided term,
+/// within an edit distance defined by [Fuzziness].
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-fuzzy-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsFuzzy {
+  term: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  prefix_length: Option<u8>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  transpositions: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  fuzziness: Option<Fuzziness>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  analyzer: Option<String>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  use_field: Option<String>,
+}
+
+impl IntervalsFuzzy {
+  /// Creates an instance of [`IntervalsFuzzy`]
+  ///
+  /// - `term` - The term to match
+  pub fn new<T>(term: T) -> Self
+  where
+    T: ToString, {
+    Self {
+      term: term.to_string(),
+      prefix_length: None,
+      transpositions: None,
+      fuzziness: None,
+      analyzer: None,
+      use_field: None,
+    }
+  }
+
+  /// Number of beginning characters left unchanged when creating
+  /// expansions. Defaults to `0`.
+  pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+    self.prefix_length = Some(prefix_length);
+    self
+  }
+
+  /// Indicates whether edits include transpositions of two adjacent
+  /// characters (ab → ba). Defaults to `true`.
+  pub fn transpositions(mut self, transpositions: bool) -> Self {
+    self.transpositions = Some(transpositions);
+    self
+  }
+
+  /// Maximum edit distance allowed for matching.
+  /// See [Fuzziness] for valid values and more information.
+  /// Defaults to `AUTO`.
+  pub fn fuzziness<T>(mut self, fuzziness: T) -> Self
+  where
+    T: Into<Fuzziness>, {
+    self.fuzziness = Some(fuzziness.into());
+    self
+  }
+
+  /// Analyzer used to normalize the `term`. Defaults to the top-level
+  /// `<field>`'s analyzer.
+  pub fn analyzer<T>(mut self, analyzer: T) -> Self
+  where
+    T: ToString, {
+    self.analyzer = Some(analyzer.to_string());
+    self
+  }
+
+  /// If specified, then match intervals from this field rather than the
+  /// top-level `<field>`.
+  pub fn use_field<T>(mut self, use_field: T) -> Self
+  where
+    T: ToString, {
+    self.use_field = Some(use_field.to_string());
+    self
+  }
+}
+
+/// The `all_of` rule returns matches that span a combination of other rules.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-all_of-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsAllOf {
+  intervals: Vec<IntervalsRule>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  max_gaps: Option<i32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  ordered: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  filter: Option<Box<IntervalsFilter>>,
+}
+
+impl IntervalsAllOf {
+  /// Creates an instance of [`IntervalsAllOf`]
+  ///
+  /// - `intervals` - An array of rules to combine. All rules must produce a
+  ///   match in a document for the overall source to match.
+  pub fn new<T>(intervals: T) -> Self
+  where
+    T: IntoIterator,
+    T::Item: Into<IntervalsRule>, {
+    Self {
+      intervals: intervals.into_iter().map(Into::into).collect(),
+      max_gaps: None,
+      ordered: None,
+      filter: None,
+    }
+  }
+
+  /// Maximum number of positions between the matching terms. Intervals
+  /// produced by the rules further apart than this are not considered
+  /// matches. Defaults to `-1`.
+  pub fn max_gaps(mut self, max_gaps: i32) -> Self {
+    self.max_gaps = Some(max_gaps);
+    self
+  }
+
+  /// If `true`, intervals produced by the rules should appear in the order in
+  /// which they are specified. Defaults to `false`.
+  pub fn ordered(mut self, ordered: bool) -> Self {
+    self.ordered = Some(ordered);
+    self
+  }
+
+  /// Rule used to filter returned intervals
+  pub fn filter<T>(mut self, filter: T) -> Self
+  where
+    T: Into<IntervalsFilter>, {
+    self.filter = Some(Box::new(filter.into()));
+    self
+  }
+}
+
+/// The `any_of` rule returns intervals produced by any of its sub-rules.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#the-any_of-rule>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IntervalsAnyOf {
+  intervals: Vec<IntervalsRule>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  filter: Option<Box<IntervalsFilter>>,
+}
+
+impl IntervalsAnyOf {
+  /// Creates an instance of [`IntervalsAnyOf`]
+  ///
+  /// - `intervals` - An array of rules to match
+  pub fn new<T>(intervals: T) -> Self
+  where
+    T: IntoIterator,
+    T::Item: Into<IntervalsRule>, {
+    Self {
+      intervals: intervals.into_iter().map(Into::into).collect(),
+      filter: None,
+    }
+  }
+
+  /// Rule used to filter returned intervals
+  pub fn filter<T>(mut self, filter: T) -> Self
+  where
+    T: Into<IntervalsFilter>, {
+    self.filter = Some(Box::new(filter.into()));
+    self
+  }
+}
+
+macro_rules! intervals_rule {
+  ($($variant:ident($rule:ty)),+ $(,)?) => {
+    $(
+      impl From<$rule> for IntervalsRule {
+        fn from(rule: $rule) -> Self {
+          Self::$variant(rule)
+        }
+      }
+    )+
+  };
+}
+
+intervals_rule!(
+  Match(IntervalsMatch),
+  Prefix(IntervalsPrefix),
+  Wildcard(IntervalsWildcard),
+  Fuzzy(IntervalsFuzzy),
+  AllOf(IntervalsAllOf),
+  AnyOf(IntervalsAnyOf),
+);
+
+/// Filters intervals produced by a matching rule based on their relation to
+/// the intervals of another rule, or based on a script.
+///
+/// <https://opensearch.org/docs/latest/query-dsl/full-text/intervals/#filter-rules>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsFilter {
+  /// Produces intervals that follow an interval from the filter rule
+  After(IntervalsRule),
+
+  /// Produces intervals that occur before an interval from the filter rule
+  Before(IntervalsRule),
+
+  /// Produces intervals contained by an interval from the filter rule
+  ContainedBy(IntervalsRule),
+
+  /// Produces intervals that contain an interval from the filter rule
+  Containing(IntervalsRule),
+
+  /// Produces intervals that are **not** contained by an interval from the
+  /// filter rule
+  NotContainedBy(IntervalsRule),
+
+  /// Produces intervals that do **not** contain an interval from the filter
+  /// rule
+  NotContaining(IntervalsRule),
+
+  /// Produces intervals that do **not** overlap with an interval from the
+  /// filter rule
+  NotOverlapping(IntervalsRule),
+
+  /// Produces intervals that overlap with an interval from the filter rule
+  Overlapping(IntervalsRule),
+
+  /// Script used to return matching documents. This script must return a
+  /// boolean value, `true` or `false`.
+  Script(Script),
+}
+
+impl IntervalsFilter {
+  /// Produces intervals that follow an interval from the filter rule
+  pub fn after<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::After(rule.into())
+  }
+
+  /// Produces intervals that occur before an interval from the filter rule
+  pub fn before<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::Before(rule.into())
+  }
+
+  /// Produces intervals contained by an interval from the filter rule
+  pub fn contained_by<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::ContainedBy(rule.into())
+  }
+
+  /// Produces intervals that contain an interval from the filter rule
+  pub fn containing<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::Containing(rule.into())
+  }
+
+  /// Produces intervals that are **not** contained by an interval from the
+  /// filter rule
+  pub fn not_contained_by<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::NotContainedBy(rule.into())
+  }
+
+  /// Produces intervals that do **not** contain an interval from the filter
+  /// rule
+  pub fn not_containing<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::NotContaining(rule.into())
+  }
+
+  /// Produces intervals that do **not** overlap with an interval from the
+  /// filter rule
+  pub fn not_overlapping<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::NotOverlapping(rule.into())
+  }
+
+  /// Produces intervals that overlap with an interval from the filter rule
+  pub fn overlapping<T>(rule: T) -> Self
+  where
+    T: Into<IntervalsRule>, {
+    Self::Overlapping(rule.into())
+  }
+}
+
+impl From<Script> for IntervalsFilter {
+  fn from(script: Script) -> Self {
+    Self::Script(script)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_query(
+      Query::intervals("my_text", IntervalsMatch::new("hot porridge")),
+      json!({
+          "intervals": {
+              "my_text": {
+                  "match": {
+                      "query": "hot porridge"
+                  }
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::intervals(
+        "my_text",
+        IntervalsAllOf::new([
+          IntervalsRule::from(IntervalsMatch::new("my favorite food").max_gaps(0).ordered(true)),
+          IntervalsRule::from(IntervalsAnyOf::new([
+            IntervalsMatch::new("hot water"),
+            IntervalsMatch::new("cold porridge"),
+          ])),
+        ])
+        .ordered(true),
+      )
+      .boost(2)
+      .name("test"),
+      json!({
+          "intervals": {
+              "my_text": {
+                  "all_of": {
+                      "ordered": true,
+                      "intervals": [
+                          {
+                              "match": {
+                                  "query": "my favorite food",
+                                  "max_gaps": 0,
+                                  "ordered": true
+                              }
+                          },
+                          {
+                              "any_of": {
+                                  "intervals": [
+                                      { "match": { "query": "hot water" } },
+                                      { "match": { "query": "cold porridge" } }
+                                  ]
+                              }
+                          }
+                      ]
+                  },
+                  "boost": 2.0,
+                  "_name": "test"
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::intervals(
+        "my_text",
+        IntervalsMatch::new("hot porridge")
+          .max_gaps(10)
+          .filter(IntervalsFilter::not_containing(IntervalsMatch::new("salty"))),
+      ),
+      json!({
+          "intervals": {
+              "my_text": {
+                  "match": {
+                      "query": "hot porridge",
+                      "max_gaps": 10,
+                      "filter": {
+                          "not_containing": {
+                              "match": {
+                                  "query": "salty"
+                              }
+                          }
+                      }
+                  }
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::intervals(
+        "my_text",
+        IntervalsAnyOf::new([
+          IntervalsRule::from(IntervalsPrefix::new("out")),
+          IntervalsRule::from(IntervalsWildcard::new("*put")),
+          IntervalsRule::from(IntervalsFuzzy::new("inptu").fuzziness(Fuzziness::Auto)),
+        ])
+        .filter(IntervalsFilter::contained_by(IntervalsMatch::new("in the garden"))),
+      ),
+      json!({
+          "intervals": {
+              "my_text": {
+                  "any_of": {
+                      "intervals": [
+                          { "prefix": { "prefix": "out" } },
+                          { "wildcard": { "pattern": "*put" } },
+                          { "fuzzy": { "term": "inptu", "fuzziness": "AUTO" } }
+                      ],
+                      "filter": {
+                          "contained_by": {
+                              "match": {
+                                  "query": "in the garden"
+                              }
+                          }
+                      }
+                  }
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn all_of_round_trip() {
+    let rule = IntervalsRule::from(
+      IntervalsAllOf::new([IntervalsMatch::new("my favorite food").max_gaps(0)])
+        .ordered(true)
+        .filter(IntervalsFilter::contained_by(IntervalsMatch::new("in a bowl"))),
+    );
+
+    let json = json!({
+        "all_of": {
+            "intervals": [
+                { "match": { "query": "my favorite food", "max_gaps": 0 } }
+            ],
+            "ordered": true,
+            "filter": {
+                "contained_by": {
+                    "match": { "query": "in a bowl" }
+                }
+            }
+        }
+    });
+
+    assert_eq!(serde_json::to_value(&rule).unwrap(), json);
+    assert_eq!(serde_json::from_value::<IntervalsRule>(json).unwrap(), rule);
+  }
+
+  #[test]
+  fn any_of_round_trip() {
+    let rule = IntervalsRule::from(IntervalsAnyOf::new([
+      IntervalsMatch::new("hot water").ordered(true),
+      IntervalsMatch::new("cold porridge").analyzer("standard"),
+    ]));
+
+    let json = json!({
+        "any_of": {
+            "intervals": [
+                { "match": { "query": "hot water", "ordered": true } },
+                { "match": { "query": "cold porridge", "analyzer": "standard" } }
+            ]
+        }
+    });
+
+    assert_eq!(serde_json::to_value(&rule).unwrap(), json);
+    assert_eq!(serde_json::from_value::<IntervalsRule>(json).unwrap(), rule);
+  }
+
+  #[test]
+  fn query_round_trip() {
+    let query = Query::intervals(
+      "my_text",
+      IntervalsAllOf::new([IntervalsMatch::new("my favorite food").max_gaps(0)]).ordered(true),
+    )
+    .boost(2)
+    .name("test");
+
+    let json = json!({
+        "intervals": {
+            "my_text": {
+                "all_of": {
+                    "intervals": [
+                        { "match": { "query": "my favorite food", "max_gaps": 0 } }
+                    ],
+                    "ordered": true
+                },
+                "boost": 2.0,
+                "_name": "test"
+            }
+        }
+    });
+
+    assert_serialize_query(query.clone(), json.clone());
+    assert_eq!(serde_json::from_value::<IntervalsQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
+}
@@ -28,11 +28,23 @@ pub struct MatchBoolPrefixQuery {
   analyzer: Option<String>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   operator: Option<Operator>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  fuzziness: Option<Fuzziness>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  prefix_length: Option<u8>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  max_expansions: Option<u8>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  fuzzy_transpositions: Option<bool>,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
 
@@ -56,6 +68,10 @@ impl Query {
       analyzer: None,
       minimum_should_match: None,
       operator: None,
+      fuzziness: None,
+      prefix_length: None,
+      max_expansions: None,
+      fuzzy_transpositions: None,
       boost: None,
       _name: None,
     }
@@ -82,8 +98,8 @@ impl MatchBoolPrefixQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -92,6 +108,37 @@ impl MatchBoolPrefixQuery {
     self.operator = Some(operator);
     self
   }
+
+  /// Maximum edit distance allowed for matching the terms of the `bool`
+  /// query. The last term, used in the `prefix` query, is not fuzzy.
+  /// See [Fuzziness](Fuzziness) for valid values and more information.
+  pub fn fuzziness<T>(mut self, fuzziness: T) -> Self
+  where
+    T: Into<Fuzziness>, {
+    self.fuzziness = Some(fuzziness.into());
+    self
+  }
+
+  /// Number of beginning characters left unchanged for fuzzy matching.
+  /// Defaults to `0`.
+  pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+    self.prefix_length = Some(prefix_length);
+    self
+  }
+
+  /// Maximum number of terms to which the query will expand.
+  /// Defaults to `50`.
+  pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    self.max_expansions = Some(max_expansions);
+    self
+  }
+
+  /// If `true`, edits for fuzzy matching include transpositions of two
+  /// adjacent characters (ab → ba). Defaults to `true`.
+  pub fn fuzzy_transpositions(mut self, fuzzy_transpositions: bool) -> Self {
+    self.fuzzy_transpositions = Some(fuzzy_transpositions);
+    self
+  }
 }
 
 impl ShouldSkip for MatchBoolPrefixQuery {
@@ -125,6 +172,7 @@ mod tests {
         .analyzer("search_time_analyzer")
         .minimum_should_match("12")
         .operator(Operator::Or)
+        .fuzziness(Fuzziness::Auto)
         .boost(2)
         .name("test"),
       json!({
@@ -134,6 +182,7 @@ mod tests {
                   "analyzer": "search_time_analyzer",
                   "minimum_should_match": "12",
                   "operator": "OR",
+                  "fuzziness": "AUTO",
                   "boost": 2.0,
                   "_name": "test"
               }
@@ -141,4 +190,28 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_and_operator_with_fuzzy_options() {
+    assert_serialize_query(
+      Query::match_bool_prefix("message", "quick brown f")
+        .operator(Operator::And)
+        .fuzziness(1)
+        .prefix_length(2)
+        .max_expansions(10)
+        .fuzzy_transpositions(false),
+      json!({
+          "match_bool_prefix": {
+              "message": {
+                  "query": "quick brown f",
+                  "operator": "AND",
+                  "fuzziness": 1,
+                  "prefix_length": 2,
+                  "max_expansions": 10,
+                  "fuzzy_transpositions": false
+              }
+          }
+      }),
+    );
+  }
 }
@@ -154,4 +154,22 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_only_set_expansion_options() {
+    assert_serialize_query(
+      Query::match_phrase_prefix("message", "quick brown f")
+        .max_expansions(50)
+        .slop(1),
+      json!({
+          "match_phrase_prefix": {
+              "message": {
+                  "query": "quick brown f",
+                  "max_expansions": 50,
+                  "slop": 1
+              }
+          }
+      }),
+    );
+  }
 }
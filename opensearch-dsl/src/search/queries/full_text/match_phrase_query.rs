@@ -28,6 +28,9 @@ pub struct MatchPhraseQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   slop: Option<u8>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  zero_terms_query: Option<ZeroTermsQuery>,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
 
@@ -57,6 +60,7 @@ impl Query {
       query: query.into(),
       analyzer: None,
       slop: None,
+      zero_terms_query: None,
       boost: None,
       _name: None,
     }
@@ -84,6 +88,13 @@ impl MatchPhraseQuery {
     self.slop = Some(slop);
     self
   }
+
+  /// Indicates whether no documents are returned if the `analyzer` removes
+  /// all tokens, such as when using a `stop` filter.
+  pub fn zero_terms_query(mut self, zero_terms_query: ZeroTermsQuery) -> Self {
+    self.zero_terms_query = Some(zero_terms_query);
+    self
+  }
 }
 
 impl ShouldSkip for MatchPhraseQuery {
@@ -131,4 +142,22 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_slop_and_zero_terms_query_under_field() {
+    assert_serialize_query(
+      Query::match_phrase("message", "this is a test")
+        .slop(2)
+        .zero_terms_query(ZeroTermsQuery::All),
+      json!({
+          "match_phrase": {
+              "message": {
+                  "query": "this is a test",
+                  "slop": 2,
+                  "zero_terms_query": "all"
+              }
+          }
+      }),
+    );
+  }
 }
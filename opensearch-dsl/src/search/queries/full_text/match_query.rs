@@ -51,7 +51,7 @@ pub struct MatchQuery {
   operator: Option<Operator>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   zero_terms_query: Option<ZeroTermsQuery>,
@@ -192,8 +192,8 @@ impl MatchQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -268,4 +268,31 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_and_operator_with_auto_fuzziness() {
+    assert_serialize_query(
+      Query::r#match("message", "this is a tset")
+        .operator(Operator::And)
+        .fuzziness(Fuzziness::Auto),
+      json!({
+          "match": {
+              "message": {
+                  "query": "this is a tset",
+                  "fuzziness": "AUTO",
+                  "operator": "AND"
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::r#match("message", "this is a test").operator(Operator::And);
+    let json = json!({ "match": { "message": { "query": "this is a test", "operator": "AND" } } });
+
+    assert_eq!(serde_json::from_value::<MatchQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
@@ -5,6 +5,7 @@
 //! <https://www.elastic.co/guide/en/opensearch/reference/current/full-text-queries.html>
 
 mod combined_fields_query;
+mod intervals_query;
 mod match_bool_prefix_query;
 mod match_phrase_prefix_query;
 mod match_phrase_query;
@@ -14,6 +15,6 @@ mod query_string_query;
 mod simple_query_string_query;
 
 pub use self::{
-  combined_fields_query::*, match_bool_prefix_query::*, match_phrase_prefix_query::*, match_phrase_query::*,
-  match_query::*, multi_match_query::*, query_string_query::*, simple_query_string_query::*,
+  combined_fields_query::*, intervals_query::*, match_bool_prefix_query::*, match_phrase_prefix_query::*,
+  match_phrase_query::*, match_query::*, multi_match_query::*, query_string_query::*, simple_query_string_query::*,
 };
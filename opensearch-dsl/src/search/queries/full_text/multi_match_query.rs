@@ -20,7 +20,7 @@ use crate::{search::*, util::*};
 #[serde(remote = "Self")]
 pub struct MultiMatchQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  fields: Vec<String>,
+  fields: Vec<MultiMatchField>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   r#type: Option<TextQueryType>,
@@ -59,7 +59,7 @@ pub struct MultiMatchQuery {
   operator: Option<Operator>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   zero_terms_query: Option<ZeroTermsQuery>,
@@ -89,7 +89,10 @@ impl Query {
     F::Item: ToString,
     S: Into<Text>, {
     MultiMatchQuery {
-      fields: fields.into_iter().map(|s| s.to_string()).collect(),
+      fields: fields
+        .into_iter()
+        .map(|s| MultiMatchField::from(s.to_string()))
+        .collect(),
       r#type: None,
       tie_breaker: None,
       query: query.into(),
@@ -113,6 +116,25 @@ impl Query {
 impl MultiMatchQuery {
   add_boost_and_name!();
 
+  /// Boosts matches on an individual field, serialized as `field^boost`.
+  ///
+  /// The field is added to the list of searched fields if it isn't there yet,
+  /// otherwise its boost is replaced.
+  pub fn field_boost<F, B>(mut self, field: F, boost: B) -> Self
+  where
+    F: ToString,
+    B: num_traits::AsPrimitive<f32>, {
+    let field = field.to_string();
+    let boost = Some(boost.as_());
+
+    match self.fields.iter_mut().find(|f| f.field == field) {
+      Some(existing) => existing.boost = boost,
+      None => self.fields.push(MultiMatchField { field, boost }),
+    }
+
+    self
+  }
+
   /// The way the multi_match query is executed internally depends on the
   /// type parameter
   pub fn r#type(mut self, r#type: TextQueryType) -> Self {
@@ -233,8 +255,8 @@ impl MultiMatchQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -252,6 +274,57 @@ impl ShouldSkip for MultiMatchQuery {
   }
 }
 
+/// A field searched by a [`MultiMatchQuery`] with an optional per-field boost
+#[derive(Debug, Clone, PartialEq)]
+struct MultiMatchField {
+  field: String,
+  boost: Option<f32>,
+}
+
+impl From<String> for MultiMatchField {
+  fn from(value: String) -> Self {
+    match value.rsplit_once('^').map(|(field, boost)| (field, boost.parse())) {
+      Some((field, Ok(boost))) => {
+        Self {
+          field: field.to_string(),
+          boost: Some(boost),
+        }
+      }
+      _ => {
+        Self {
+          field: value,
+          boost: None,
+        }
+      }
+    }
+  }
+}
+
+impl std::fmt::Display for MultiMatchField {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.boost {
+      Some(boost) => write!(f, "{}^{}", self.field, boost),
+      None => self.field.fmt(f),
+    }
+  }
+}
+
+impl serde::Serialize for MultiMatchField {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer, {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for MultiMatchField {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>, {
+    <String as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+  }
+}
+
 serialize_with_root!("multi_match": MultiMatchQuery);
 deserialize_with_root!("multi_match": MultiMatchQuery);
 
@@ -311,4 +384,48 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_cross_fields_with_tie_breaker() {
+    assert_serialize_query(
+      Query::multi_match(["first_name", "last_name"], "Will Smith")
+        .r#type(TextQueryType::CrossFields)
+        .tie_breaker(0.3)
+        .operator(Operator::And),
+      json!({
+          "multi_match": {
+              "query": "Will Smith",
+              "fields": ["first_name", "last_name"],
+              "type": "cross_fields",
+              "tie_breaker": 0.3,
+              "operator": "AND"
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_field_boosts() {
+    assert_serialize_query(
+      Query::multi_match(["title", "body"], "quick brown fox")
+        .field_boost("title", 3)
+        .field_boost("summary", 1.5),
+      json!({
+          "multi_match": {
+              "query": "quick brown fox",
+              "fields": ["title^3", "body", "summary^1.5"]
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::multi_match(["title^2", "body"], "quick brown fox").field_boost("title", 4),
+      json!({
+          "multi_match": {
+              "query": "quick brown fox",
+              "fields": ["title^4", "body"]
+          }
+      }),
+    );
+  }
 }
@@ -65,7 +65,7 @@ pub struct QueryStringQuery {
   max_determinized_states: Option<u32>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   quote_analyzer: Option<String>,
@@ -294,8 +294,8 @@ impl QueryStringQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -417,4 +417,22 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_fields_with_and_operator() {
+    assert_serialize_query(
+      Query::query_string("(new york city) OR (big apple*)")
+        .fields(["title^2", "content"])
+        .default_operator(Operator::And)
+        .analyze_wildcard(true),
+      json!({
+          "query_string": {
+              "query": "(new york city) OR (big apple*)",
+              "analyze_wildcard": true,
+              "default_operator": "AND",
+              "fields": ["title^2", "content"]
+          }
+      }),
+    );
+  }
 }
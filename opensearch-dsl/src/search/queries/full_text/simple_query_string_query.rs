@@ -40,7 +40,8 @@ pub struct SimpleQueryStringQuery {
   #[serde(
     default,
     skip_serializing_if = "ShouldSkip::should_skip",
-    serialize_with = "join_with_pipe"
+    serialize_with = "join_with_pipe",
+    deserialize_with = "split_with_pipe"
   )]
   flags: Vec<SimpleQueryStringQueryFlags>,
 
@@ -57,7 +58,7 @@ pub struct SimpleQueryStringQuery {
   lenient: Option<bool>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   quote_field_suffix: Option<String>,
@@ -212,8 +213,8 @@ impl SimpleQueryStringQuery {
   /// information.
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -291,4 +292,20 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_or_and_prefix_flags() {
+    assert_serialize_query(
+      Query::simple_query_string("foo | bar*")
+        .fields(["title"])
+        .flags([SimpleQueryStringQueryFlags::Or, SimpleQueryStringQueryFlags::Prefix]),
+      json!({
+          "simple_query_string": {
+              "query": "foo | bar*",
+              "fields": ["title"],
+              "flags": "OR|PREFIX"
+          }
+      }),
+    );
+  }
 }
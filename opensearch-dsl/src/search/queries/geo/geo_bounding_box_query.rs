@@ -203,8 +203,8 @@ mod tests {
               "validation_method": "STRICT",
               "_name": "test_name",
               "pin.location": {
-                  "top_left": [ -74.1, 40.73 ],
-                  "bottom_right": [ -71.12, 40.01 ]
+                  "top_left": { "lat": 40.73, "lon": -74.1 },
+                  "bottom_right": { "lat": 40.01, "lon": -71.12 }
               }
           }
       }),
@@ -238,4 +238,53 @@ mod tests {
       }),
     )
   }
+
+  #[test]
+  fn serializes_wkt_form() {
+    assert_serialize_query(
+      Query::geo_bounding_box("pin.location", GeoBoundingBox::bbox(40.73, -74.1, 40.01, -71.12))
+        .validation_method(ValidationMethod::IgnoreMalformed),
+      json!({
+          "geo_bounding_box": {
+              "validation_method": "IGNORE_MALFORMED",
+              "pin.location": {
+                  "wkt": "BBOX (-74.1, -71.12, 40.73, 40.01)"
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::geo_bounding_box(
+        "pin.location",
+        GeoBoundingBox::wkt("BBOX (-74.1, -71.12, 40.73, 40.01)"),
+      ),
+      json!({
+          "geo_bounding_box": {
+              "pin.location": {
+                  "wkt": "BBOX (-74.1, -71.12, 40.73, 40.01)"
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_edges_form() {
+    assert_serialize_query(
+      Query::geo_bounding_box("pin.location", GeoBoundingBox::edges(40.73, -74.1, 40.01, -71.12))
+        .validation_method(ValidationMethod::Coerce),
+      json!({
+          "geo_bounding_box": {
+              "validation_method": "COERCE",
+              "pin.location": {
+                  "top": 40.73,
+                  "left": -74.1,
+                  "bottom": 40.01,
+                  "right": -71.12
+              }
+          }
+      }),
+    );
+  }
 }
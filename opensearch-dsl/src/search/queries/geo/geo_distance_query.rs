@@ -216,12 +216,12 @@ mod tests {
       Query::geo_distance(
         "pin.location",
         GeoLocation::new(40.12, -71.34),
-        Distance::Kilometers(300),
+        Distance::Kilometers(300.0),
       ),
       json!({
           "geo_distance": {
               "distance": "300km",
-              "pin.location": [-71.34, 40.12],
+              "pin.location": { "lat": 40.12, "lon": -71.34 },
           }
       }),
     );
@@ -230,7 +230,7 @@ mod tests {
       Query::geo_distance(
         "pin.location",
         GeoLocation::new(40.12, -71.34),
-        Distance::Kilometers(300),
+        Distance::Kilometers(300.0),
       )
       .distance_type(GeoDistanceType::Plane)
       .validation_method(ValidationMethod::Strict)
@@ -240,7 +240,7 @@ mod tests {
           "geo_distance": {
               "distance": "300km",
               "distance_type": "plane",
-              "pin.location": [-71.34, 40.12],
+              "pin.location": { "lat": 40.12, "lon": -71.34 },
               "validation_method": "STRICT",
               "_name": "test_name",
               "boost": 1.0,
@@ -248,4 +248,38 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_plane_distance_with_unit() {
+    assert_serialize_query(
+      Query::geo_distance(
+        "pin.location",
+        GeoLocation::new(40.12, -71.34),
+        Distance::new(12.0, DistanceUnit::Kilometers),
+      )
+      .distance_type(GeoDistanceType::Plane)
+      .validation_method(ValidationMethod::Coerce),
+      json!({
+          "geo_distance": {
+              "distance": "12km",
+              "distance_type": "plane",
+              "validation_method": "COERCE",
+              "pin.location": { "lat": 40.12, "lon": -71.34 },
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn deserializes_distance_units() {
+    for (value, expected) in [
+      ("1m", Distance::new(1.0, DistanceUnit::Meters)),
+      ("12km", Distance::new(12.0, DistanceUnit::Kilometers)),
+      ("1.5km", Distance::new(1.5, DistanceUnit::Kilometers)),
+      ("3nmi", Distance::new(3.0, DistanceUnit::NauticalMiles)),
+      ("100", Distance::new(100.0, DistanceUnit::Meters)),
+    ] {
+      assert_eq!(serde_json::from_value::<Distance>(json!(value)).unwrap(), expected);
+    }
+  }
 }
@@ -0,0 +1,215 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{search::*, util::*};
+
+/// Matches [geo_point](https://opensearch.org/docs/latest/field-types/supported-field-types/geo-point/)
+/// values that fall within a polygon defined by a list of points.
+///
+/// To create a geo polygon query:
+/// ```
+/// # use opensearch_dsl::*;
+/// # let query =
+/// Query::geo_polygon(
+///   "person.location",
+///   [[-70.0, 40.0], [-80.0, 30.0], [-90.0, 20.0]],
+/// )
+/// .validation_method(ValidationMethod::Coerce);
+/// ```
+/// <https://opensearch.org/docs/latest/query-dsl/geo-and-xy/geopolygon/>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct GeoPolygonQuery {
+  #[serde(skip)]
+  field: String,
+
+  #[serde(skip)]
+  polygon: GeoPolygon,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  validation_method: Option<ValidationMethod>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+struct GeoPolygon {
+  points: Vec<GeoLocation>,
+}
+
+impl Query {
+  /// Creates an instance of [`GeoPolygonQuery`]
+  ///
+  /// - `field` - Field you wish to search.
+  /// - `points` - Vertices of the polygon, the polygon is closed automatically
+  pub fn geo_polygon<T, I>(field: T, points: I) -> GeoPolygonQuery
+  where
+    T: ToString,
+    I: IntoIterator,
+    I::Item: Into<GeoLocation>, {
+    GeoPolygonQuery {
+      field: field.to_string(),
+      polygon: GeoPolygon {
+        points: points.into_iter().map(Into::into).collect(),
+      },
+      validation_method: None,
+      boost: None,
+      _name: None,
+    }
+  }
+}
+
+impl GeoPolygonQuery {
+  add_boost_and_name!();
+
+  /// Set to `IGNORE_MALFORMED` to accept geo points with invalid latitude or
+  /// longitude, set to `COERCE` to also try to infer correct latitude or
+  /// longitude. (default is `STRICT`).
+  pub fn validation_method(mut self, validation_method: ValidationMethod) -> Self {
+    self.validation_method = Some(validation_method);
+    self
+  }
+}
+
+impl ShouldSkip for GeoPolygonQuery {}
+
+serialize_with_root_key_value_pair!("geo_polygon": GeoPolygonQuery, field, polygon);
+impl<'de> Deserialize<'de> for GeoPolygonQuery {
+  fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+  where
+    D: Deserializer<'de>, {
+    use std::fmt;
+    struct WrapperVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for WrapperVisitor {
+      type Value = GeoPolygonQuery;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct geo_polygon")
+      }
+
+      fn visit_map<A>(self, mut map: A) -> Result<GeoPolygonQuery, A::Error>
+      where
+        A: serde::de::MapAccess<'de>, {
+        let mut query: GeoPolygonQuery = GeoPolygonQuery::default();
+        let mut found_value = false;
+
+        while let Some(key) = map.next_key::<String>()? {
+          if key == "geo_polygon" {
+            let inner_map = map.next_value::<serde_json::Map<String, serde_json::Value>>()?;
+            for (k, v) in inner_map.iter() {
+              match k.as_str() {
+                "boost" => {
+                  match v.as_f64() {
+                    Some(boost) => {
+                      query.boost = Some(boost as f32);
+                    }
+                    None => {
+                      return Err(serde::de::Error::invalid_type(
+                        serde::de::Unexpected::Other("not a float"),
+                        &"a float",
+                      ));
+                    }
+                  }
+                }
+                "_name" => {
+                  match v.as_str() {
+                    Some(_name) => {
+                      query._name = Some(_name.to_string());
+                    }
+                    None => {
+                      return Err(serde::de::Error::invalid_type(
+                        serde::de::Unexpected::Other("not a string"),
+                        &"a string",
+                      ));
+                    }
+                  }
+                }
+                "validation_method" => {
+                  let validation_method = serde_json::from_value::<ValidationMethod>(v.clone());
+                  match validation_method {
+                    Ok(validation_method) => {
+                      query.validation_method = Some(validation_method);
+                    }
+                    Err(e) => {
+                      return Err(serde::de::Error::custom(format!(
+                        "error parsing validation_method: {}",
+                        e
+                      )));
+                    }
+                  }
+                }
+                _ => {
+                  query.field = k.to_owned();
+                  let value = serde_json::from_value::<GeoPolygon>(v.clone());
+                  match value {
+                    Ok(value) => {
+                      query.polygon = value;
+                      found_value = true;
+                    }
+                    Err(e) => {
+                      return Err(serde::de::Error::custom(format!("error parsing {}: {}", k, e)));
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+        if found_value {
+          Ok(query)
+        } else {
+          Err(serde::de::Error::missing_field("points value"))
+        }
+      }
+    }
+
+    deserializer.deserialize_struct("Wrapper", &["geo_polygon"], WrapperVisitor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize_query(
+      Query::geo_polygon(
+        "person.location",
+        [
+          GeoLocation::new(40.0, -70.0),
+          GeoLocation::new(30.0, -80.0),
+          GeoLocation::new(20.0, -90.0),
+        ],
+      ),
+      json!({
+          "geo_polygon": {
+              "person.location": {
+                  "points": [{ "lat": 40.0, "lon": -70.0 }, { "lat": 30.0, "lon": -80.0 }, { "lat": 20.0, "lon": -90.0 }]
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::geo_polygon("person.location", [(-70.0, 40.0), (-80.0, 30.0), (-90.0, 20.0)])
+        .validation_method(ValidationMethod::IgnoreMalformed)
+        .boost(2)
+        .name("triangle"),
+      json!({
+          "geo_polygon": {
+              "validation_method": "IGNORE_MALFORMED",
+              "boost": 2.0,
+              "_name": "triangle",
+              "person.location": {
+                  "points": [{ "lat": 40.0, "lon": -70.0 }, { "lat": 30.0, "lon": -80.0 }, { "lat": 20.0, "lon": -90.0 }]
+              }
+          }
+      }),
+    );
+  }
+}
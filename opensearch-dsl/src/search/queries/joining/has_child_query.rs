@@ -17,7 +17,7 @@ use crate::{search::*, util::*};
 pub struct HasChildQuery {
   r#type: String,
 
-  query: Box<Query>,
+  pub(crate) query: Box<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   ignore_unmapped: Option<bool>,
@@ -46,8 +46,8 @@ impl Query {
   ///
   /// - `type` - Name of the child relationship mapped for the join field.
   /// - `query` - Query you wish to run on child documents of the `type` field.
-  ///   If a child
-  /// document matches the search, the query returns the parent document.
+  ///   If a child document matches the search, the query returns the parent
+  ///   document.
   pub fn has_child<T, U>(r#type: T, query: U) -> HasChildQuery
   where
     T: ToString,
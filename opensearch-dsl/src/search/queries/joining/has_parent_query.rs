@@ -17,7 +17,7 @@ use crate::{search::*, util::*};
 pub struct HasParentQuery {
   parent_type: String,
 
-  query: Box<Query>,
+  pub(crate) query: Box<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   score: Option<bool>,
@@ -25,6 +25,9 @@ pub struct HasParentQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   ignore_unmapped: Option<bool>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  inner_hits: Option<Box<InnerHits>>,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
 
@@ -38,8 +41,8 @@ impl Query {
   /// - `parent-type` - Name of the parent relationship mapped for the join
   ///   field.
   /// - `query` - Query you wish to run on parent documents of the `parent_type`
-  ///   field. If a
-  /// parent document matches the search, the query returns its child documents.
+  ///   field. If a parent document matches the search, the query returns its
+  ///   child documents.
   pub fn has_parent<T, U>(parent_type: T, query: U) -> HasParentQuery
   where
     T: ToString,
@@ -49,6 +52,7 @@ impl Query {
       query: Box::new(query.into()),
       score: None,
       ignore_unmapped: None,
+      inner_hits: None,
       boost: None,
       _name: None,
     }
@@ -83,6 +87,32 @@ impl HasParentQuery {
     self.ignore_unmapped = Some(ignore_unmapped);
     self
   }
+
+  /// The [parent-join](https://www.elastic.co/guide/en/opensearch/reference/current/parent-join.html)
+  /// and [nested](https://www.elastic.co/guide/en/opensearch/reference/current/nested.html)
+  /// features allow the return of documents that have matches in a different
+  /// scope. In the parent/child case, parent documents are returned based on
+  /// matches in child documents or child documents are returned based on
+  /// matches in parent documents. In the nested case, documents are returned
+  /// based on matches in nested inner objects.
+  ///
+  /// In both cases, the actual matches in the different scopes that caused a
+  /// document to be returned are hidden. In many cases, it’s very useful to
+  /// know which inner nested objects (in the case of nested) or
+  /// children/parent documents (in the case of parent/child) caused
+  /// certain information to be returned. The inner hits feature can be used for
+  /// this. This feature returns per search hit in the search response
+  /// additional nested hits that caused a search hit to match in a different
+  /// scope.
+  ///
+  /// Inner hits can be used by defining an `inner_hits` definition on a
+  /// `nested`, `has_child` or `has_parent` query and filter.
+  ///
+  /// <https://www.elastic.co/guide/en/opensearch/reference/current/inner-hits.html>
+  pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+    self.inner_hits = Some(Box::new(inner_hits));
+    self
+  }
 }
 
 impl ShouldSkip for HasParentQuery {
@@ -140,4 +170,30 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_score_with_inner_hits() {
+    assert_serialize_query(
+      Query::has_parent("question", Query::term("tag", "rust"))
+        .score(true)
+        .inner_hits(InnerHits::new().name("questions").size(1)),
+      json!({
+          "has_parent": {
+              "parent_type": "question",
+              "score": true,
+              "query": {
+                  "term": {
+                      "tag": {
+                          "value": "rust"
+                      }
+                  }
+              },
+              "inner_hits": {
+                  "name": "questions",
+                  "size": 1
+              }
+          }
+      }),
+    );
+  }
 }
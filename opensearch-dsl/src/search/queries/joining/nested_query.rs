@@ -38,7 +38,7 @@ use crate::{search::*, util::*};
 pub struct NestedQuery {
   path: String,
 
-  query: Box<Query>,
+  pub(crate) query: Box<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   score_mode: Option<NestedQueryScoreMode>,
@@ -212,4 +212,32 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_score_mode_and_inner_hits() {
+    assert_serialize_query(
+      Query::nested("comments", Query::r#match("comments.text", "great"))
+        .score_mode(NestedQueryScoreMode::Sum)
+        .ignore_unmapped(true)
+        .inner_hits(InnerHits::new().name("matching_comments").size(2)),
+      json!({
+          "nested": {
+              "path": "comments",
+              "query": {
+                  "match": {
+                      "comments.text": {
+                          "query": "great"
+                      }
+                  }
+              },
+              "score_mode": "sum",
+              "ignore_unmapped": true,
+              "inner_hits": {
+                  "name": "matching_comments",
+                  "size": 2
+              }
+          }
+      }),
+    );
+  }
 }
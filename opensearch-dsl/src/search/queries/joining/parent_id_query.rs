@@ -11,7 +11,7 @@ use crate::{search::*, util::*};
 /// # let query =
 /// Query::parent_id("test", 1);
 /// ```
-/// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-ParentId-query.html>
+/// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-parent-id-query.html>
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct ParentIdQuery {
@@ -34,8 +34,7 @@ impl Query {
   ///
   /// - `type` - Name of the child relationship mapped for the join field
   /// - `id` - ID of the parent document. The query will return child documents
-  ///   of this
-  /// parent document.
+  ///   of this parent document.
   pub fn parent_id<T, U>(r#type: T, id: U) -> ParentIdQuery
   where
     T: ToString,
@@ -107,4 +106,18 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_ignore_unmapped_only_when_set() {
+    assert_serialize_query(
+      Query::parent_id("answer", "q-1").ignore_unmapped(true),
+      json!({
+          "parent_id": {
+              "type": "answer",
+              "id": "q-1",
+              "ignore_unmapped": true
+          }
+      }),
+    );
+  }
 }
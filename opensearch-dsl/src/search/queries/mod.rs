@@ -41,6 +41,15 @@ pub use self::span::*;
 pub use self::specialized::*;
 pub use self::term_level::*;
 
+// Query tree analysis
+mod complexity;
+mod validation;
+mod visitor;
+
+pub use self::complexity::*;
+pub use self::validation::*;
+pub use self::visitor::*;
+
 // Very special queries
 mod match_all_query;
 mod match_none_query;
@@ -187,11 +196,11 @@ query!(
     Fuzzy(FuzzyQuery),
     GeoDistance(GeoDistanceQuery),
     GeoBoundingBox(GeoBoundingBoxQuery),
+    GeoPolygon(GeoPolygonQuery),
     GeoShapeLookup(GeoShapeLookupQuery),
     GeoShape(GeoShapeQuery),
     ShapeLookup(ShapeLookupQuery),
     Shape(ShapeQuery),
-    Json(JsonQuery),
     Wrapper(WrapperQuery),
     Script(ScriptQuery),
     ScriptScore(ScriptScoreQuery),
@@ -201,6 +210,7 @@ query!(
     SimpleQueryString(SimpleQueryStringQuery),
     QueryString(QueryStringQuery),
     CombinedFields(CombinedFieldsQuery),
+    Intervals(IntervalsQuery),
     SpanContaining(SpanContainingQuery),
     SpanFieldMasking(SpanFieldMaskingQuery),
     SpanFirst(SpanFirstQuery),
@@ -211,6 +221,8 @@ query!(
     SpanTerm(SpanTermQuery),
     SpanWithin(SpanWithinQuery),
     Knn(KnnQuery),
+    // Catch-all for raw JSON, kept last so typed variants are tried first
+    Json(JsonQuery),
 );
 
 #[cfg(test)]
@@ -228,4 +240,16 @@ mod tests {
       Query::term("field", "value"),
     );
   }
+
+  #[test]
+  fn deserializes_variants_declared_after_json() {
+    for query in [
+      Query::from(Query::script(crate::Script::source("doc['likes'].value > 10"))),
+      Query::from(Query::span_term("user", "kimchy")),
+      Query::from(Query::knn("vector", vec![1.0, 2.0]).num_candidates(10)),
+    ] {
+      let json = serde_json::to_value(&query).unwrap();
+      assert_eq!(serde_json::from_value::<Query>(json).unwrap(), query);
+    }
+  }
 }
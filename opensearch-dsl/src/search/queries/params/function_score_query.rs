@@ -165,6 +165,55 @@ impl Function {
     T: ToString, {
     FunctionScoreScript::new(source)
   }
+
+  /// Query restricting the documents the function applies to
+  pub(crate) fn filter_query(&self) -> Option<&Query> {
+    match self {
+      Self::Weight(f) => f.filter.as_ref(),
+      Self::RandomScore(f) => f.filter.as_ref(),
+      Self::FieldValueFactor(f) => f.filter.as_ref(),
+      Self::DecayDateTime(f) => f.filter.as_ref(),
+      Self::DecayLocation(f) => f.filter.as_ref(),
+      Self::DecayI8(f) => f.filter.as_ref(),
+      Self::DecayI16(f) => f.filter.as_ref(),
+      Self::DecayI32(f) => f.filter.as_ref(),
+      Self::DecayI64(f) => f.filter.as_ref(),
+      Self::DecayU8(f) => f.filter.as_ref(),
+      Self::DecayU16(f) => f.filter.as_ref(),
+      Self::DecayU32(f) => f.filter.as_ref(),
+      Self::DecayU64(f) => f.filter.as_ref(),
+      Self::Script(_) => None,
+    }
+  }
+
+  /// Rewrites the query restricting the documents the function applies to
+  pub(crate) fn map_filter_query<F>(self, map: F) -> Self
+  where
+    F: FnOnce(Query) -> Query, {
+    macro_rules! map_filter {
+      ($variant:ident, $f:ident) => {{
+        $f.filter = $f.filter.map(map);
+        Self::$variant($f)
+      }};
+    }
+
+    match self {
+      Self::Weight(mut f) => map_filter!(Weight, f),
+      Self::RandomScore(mut f) => map_filter!(RandomScore, f),
+      Self::FieldValueFactor(mut f) => map_filter!(FieldValueFactor, f),
+      Self::DecayDateTime(mut f) => map_filter!(DecayDateTime, f),
+      Self::DecayLocation(mut f) => map_filter!(DecayLocation, f),
+      Self::DecayI8(mut f) => map_filter!(DecayI8, f),
+      Self::DecayI16(mut f) => map_filter!(DecayI16, f),
+      Self::DecayI32(mut f) => map_filter!(DecayI32, f),
+      Self::DecayI64(mut f) => map_filter!(DecayI64, f),
+      Self::DecayU8(mut f) => map_filter!(DecayU8, f),
+      Self::DecayU16(mut f) => map_filter!(DecayU16, f),
+      Self::DecayU32(mut f) => map_filter!(DecayU32, f),
+      Self::DecayU64(mut f) => map_filter!(DecayU64, f),
+      Self::Script(f) => Self::Script(f),
+    }
+  }
 }
 
 /// The `weight` score allows you to multiply the score by the provided weight.
@@ -175,7 +224,7 @@ impl Function {
 pub struct Weight {
   weight: f32,
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  filter: Option<Query>,
+  pub(crate) filter: Option<Query>,
 }
 
 impl Weight {
@@ -216,7 +265,7 @@ pub struct RandomScore {
   random_score: RandomScoreInner,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  filter: Option<Query>,
+  pub(crate) filter: Option<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   weight: Option<f32>,
@@ -281,7 +330,8 @@ impl RandomScore {
 ///
 /// As an example, imagine you have a document indexed with a numeric `my-int`
 /// field and wish to influence the score of a document with this field, an
-/// example doing so would look like: ```
+/// example doing so would look like:
+/// ```
 /// # use opensearch_dsl::{FieldValueFactor, FieldValueFactorModifier};
 /// # fn main() {
 /// # let _ =
@@ -300,7 +350,7 @@ pub struct FieldValueFactor {
   field_value_factor: FieldValueFactorInner,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  filter: Option<Query>,
+  pub(crate) filter: Option<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   weight: Option<f32>,
@@ -470,7 +520,7 @@ pub struct Decay<T: Origin + DeserializeOwned> {
 
   inner: DecayFieldInner<T>,
 
-  filter: Option<Query>,
+  pub(crate) filter: Option<Query>,
 
   weight: Option<f32>,
 }
@@ -63,7 +63,13 @@ impl<'de> Deserialize<'de> for Fuzziness {
       type Value = Fuzziness;
 
       fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a string representing a Fuzziness variant")
+        formatter.write_str("a string or number representing a Fuzziness variant")
+      }
+
+      fn visit_u64<E>(self, value: u64) -> Result<Fuzziness, E>
+      where
+        E: Error, {
+        u8::try_from(value).map(Fuzziness::Distance).map_err(E::custom)
       }
 
       fn visit_str<E>(self, value: &str) -> Result<Fuzziness, E>
@@ -87,7 +93,7 @@ impl<'de> Deserialize<'de> for Fuzziness {
       }
     }
 
-    deserializer.deserialize_str(FuzzinessVisitor)
+    deserializer.deserialize_any(FuzzinessVisitor)
   }
 }
 
@@ -139,4 +145,18 @@ mod tests {
       json!(["AUTO", "AUTO:0,2", 5,]),
     )
   }
+
+  #[test]
+  fn deserializes() {
+    let result: Vec<Fuzziness> = serde_json::from_value(json!(["AUTO", "AUTO:3,6", 2, "2"])).unwrap();
+
+    let expectation = vec![
+      Fuzziness::Auto,
+      Fuzziness::Range(3, 6),
+      Fuzziness::Distance(2),
+      Fuzziness::Distance(2),
+    ];
+
+    assert_eq!(result, expectation);
+  }
 }
@@ -59,6 +59,34 @@ pub enum GeoBoundingBox {
   },
 }
 
+impl GeoBoundingBox {
+  /// Creates a [`GeoBoundingBox::WellKnownText`] from a raw WKT string, e.g.
+  /// `BBOX (-74.1, -71.12, 40.73, 40.01)`
+  pub fn wkt<T>(wkt: T) -> Self
+  where
+    T: ToString, {
+    Self::WellKnownText { wkt: wkt.to_string() }
+  }
+
+  /// Creates a [`GeoBoundingBox::WellKnownText`] in the
+  /// `BBOX (left, right, top, bottom)` form from the edges of the box
+  pub fn bbox(top: f32, left: f32, bottom: f32, right: f32) -> Self {
+    Self::WellKnownText {
+      wkt: format!("BBOX ({left}, {right}, {top}, {bottom})"),
+    }
+  }
+
+  /// Creates a [`GeoBoundingBox::Vertices`] from the edges of the box
+  pub fn edges(top: f32, left: f32, bottom: f32, right: f32) -> Self {
+    Self::Vertices {
+      top,
+      left,
+      bottom,
+      right,
+    }
+  }
+}
+
 impl Default for GeoBoundingBox {
   fn default() -> Self {
     GeoBoundingBox::MainDiagonal {
@@ -81,8 +109,8 @@ mod tests {
         bottom_right: GeoLocation::new(40.01, -71.12),
       },
       json!({
-          "top_left": [-74.1, 40.73],
-          "bottom_right": [-71.12, 40.01]
+          "top_left": { "lat": 40.73, "lon": -74.1 },
+          "bottom_right": { "lat": 40.01, "lon": -71.12 }
       }),
     );
 
@@ -1,4 +1,4 @@
-use crate::{search::*, util::*, Set};
+use crate::{search::*, util::*};
 
 /// The [parent-join](https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html)
 /// and [nested](https://www.elastic.co/guide/en/elasticsearch/reference/current/nested.html)
@@ -38,10 +38,10 @@ pub struct InnerHits {
   sort: SortCollection,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  highlight: Option<Highlight>,
+  highlight: Highlight,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  docvalue_fields: Set<String>,
+  docvalue_fields: Vec<DocValueField>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   collapse: Option<InnerHitsCollapse>,
@@ -104,7 +104,7 @@ impl InnerHits {
   pub fn highlight<T>(mut self, highlight: T) -> Self
   where
     T: Into<Highlight>, {
-    self.highlight = Some(highlight.into());
+    self.highlight = highlight.into();
     self
   }
 
@@ -112,10 +112,16 @@ impl InnerHits {
   pub fn docvalue_fields<T>(mut self, docvalue_fields: T) -> Self
   where
     T: IntoIterator,
-    T::Item: ToString, {
+    T::Item: Into<DocValueField>, {
+    self.docvalue_fields.extend(docvalue_fields.into_iter().map(Into::into));
     self
-      .docvalue_fields
-      .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+  }
+
+  /// Adds a single docvalue field, optionally with a custom format
+  pub fn docvalue_field<T>(mut self, docvalue_field: T) -> Self
+  where
+    T: Into<DocValueField>, {
+    self.docvalue_fields.push(docvalue_field.into());
     self
   }
 
@@ -129,3 +135,29 @@ impl InnerHits {
     self
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize(InnerHits::new(), json!({}));
+
+    assert_serialize(
+      InnerHits::new()
+        .name("latest_comments")
+        .size(3)
+        .sort([FieldSort::descending("comments.date")])
+        .source(false),
+      json!({
+          "_source": false,
+          "name": "latest_comments",
+          "size": 3,
+          "sort": [
+              { "comments.date": { "order": "desc" } }
+          ]
+      }),
+    );
+  }
+}
@@ -1,16 +1,19 @@
 /// Indicates how the range query matches values for range fields.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "lowercase")]
 pub enum RangeRelation {
   /// Matches documents with a range field value that intersects the query’s
   /// range.
+  #[serde(alias = "INTERSECTS")]
   Intersects,
 
   /// Matches documents with a range field value that entirely contains the
   /// query’s range.
+  #[serde(alias = "CONTAINS")]
   Contains,
 
   /// Matches documents with a range field value entirely within the query’s
   /// range.
+  #[serde(alias = "WITHIN")]
   Within,
 }
@@ -39,6 +39,12 @@ pub enum RegexpFlag {
   ///
   /// `@&~(abc.+)  # matches everything except terms beginning with 'abc'`
   Anystring,
+
+  /// Enables the `#` operator, which matches the empty language.
+  Empty,
+
+  /// Disables all operators.
+  None,
 }
 
 impl From<RegexpFlag> for &'static str {
@@ -49,6 +55,8 @@ impl From<RegexpFlag> for &'static str {
       RegexpFlag::Interval => "INTERVAL",
       RegexpFlag::Intersection => "INTERSECTION",
       RegexpFlag::Anystring => "ANYSTRING",
+      RegexpFlag::Empty => "EMPTY",
+      RegexpFlag::None => "NONE",
     }
   }
 }
@@ -95,10 +103,20 @@ impl<'de> Deserialize<'de> for RegexpFlag {
           "INTERVAL" => Ok(RegexpFlag::Interval),
           "INTERSECTION" => Ok(RegexpFlag::Intersection),
           "ANYSTRING" => Ok(RegexpFlag::Anystring),
+          "EMPTY" => Ok(RegexpFlag::Empty),
+          "NONE" => Ok(RegexpFlag::None),
           _ => {
             Err(de::Error::unknown_variant(
               value,
-              &["ALL", "COMPLEMENT", "INTERVAL", "INTERSECTION", "ANYSTRING"],
+              &[
+                "ALL",
+                "COMPLEMENT",
+                "INTERVAL",
+                "INTERSECTION",
+                "ANYSTRING",
+                "EMPTY",
+                "NONE",
+              ],
             ))
           }
         }
@@ -139,15 +139,17 @@ impl<'de> Deserialize<'de> for Rewrite {
           "constant_score_boolean" => Ok(Rewrite::ConstantScoreBoolean),
           "scoring_boolean" => Ok(Rewrite::ScoringBoolean),
           s if s.starts_with("top_terms_blended_freqs_") => {
-            let n = s[23..].parse::<u64>().map_err(E::custom)?;
+            let n = s["top_terms_blended_freqs_".len()..]
+              .parse::<u64>()
+              .map_err(E::custom)?;
             Ok(Rewrite::TopTermsBlendedFrequencies(n))
           }
           s if s.starts_with("top_terms_boost_") => {
-            let n = s[15..].parse::<u64>().map_err(E::custom)?;
+            let n = s["top_terms_boost_".len()..].parse::<u64>().map_err(E::custom)?;
             Ok(Rewrite::TopTermsBoost(n))
           }
           s if s.starts_with("top_terms_") => {
-            let n = s[10..].parse::<u64>().map_err(E::custom)?;
+            let n = s["top_terms_".len()..].parse::<u64>().map_err(E::custom)?;
             Ok(Rewrite::TopTerms(n))
           }
           _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
@@ -158,3 +160,47 @@ impl<'de> Deserialize<'de> for Rewrite {
     deserializer.deserialize_str(RewriteVisitor)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::util::assert_serialize;
+
+  #[test]
+  fn serializes() {
+    assert_serialize(
+      [
+        Rewrite::ConstantScore,
+        Rewrite::TopTermsBlendedFrequencies(5),
+        Rewrite::TopTermsBoost(7),
+        Rewrite::TopTerms(10),
+      ],
+      json!([
+        "constant_score",
+        "top_terms_blended_freqs_5",
+        "top_terms_boost_7",
+        "top_terms_10"
+      ]),
+    )
+  }
+
+  #[test]
+  fn deserializes_numeric_suffix() {
+    let result: Vec<Rewrite> = serde_json::from_value(json!([
+      "scoring_boolean",
+      "top_terms_blended_freqs_5",
+      "top_terms_boost_7",
+      "top_terms_10"
+    ]))
+    .unwrap();
+
+    let expectation = vec![
+      Rewrite::ScoringBoolean,
+      Rewrite::TopTermsBlendedFrequencies(5),
+      Rewrite::TopTermsBoost(7),
+      Rewrite::TopTerms(10),
+    ];
+
+    assert_eq!(result, expectation);
+  }
+}
@@ -91,6 +91,15 @@ impl Script {
     }
     self
   }
+
+  /// Specifies all named parameters at once, replacing existing parameters
+  /// with the same names
+  pub fn params(mut self, params: serde_json::Map<String, serde_json::Value>) -> Self {
+    for (name, param) in params {
+      let _ = self.params.insert(name, param);
+    }
+    self
+  }
 }
 /// Available scripting language
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -239,4 +248,44 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn later_params_replace_earlier_ones() {
+    assert_serialize(
+      Script::source("params.a + params.b")
+        .param("a", 1)
+        .param("b", 1)
+        .params(json!({ "a": 2 }).as_object().unwrap().clone()),
+      json!({
+          "source": "params.a + params.b",
+          "params": { "a": 2, "b": 1 }
+      }),
+    );
+  }
+
+  #[test]
+  fn deserializes_inline_and_stored_scripts() {
+    let params = json!({ "factor": 2 });
+
+    assert_eq!(
+      serde_json::from_value::<Script>(json!({
+          "source": "doc['price'].value * params.factor",
+          "lang": "painless",
+          "params": { "factor": 2 }
+      }))
+      .unwrap(),
+      Script::source("doc['price'].value * params.factor")
+        .lang(ScriptLang::Painless)
+        .params(params.as_object().unwrap().clone())
+    );
+
+    assert_eq!(
+      serde_json::from_value::<Script>(json!({
+          "id": "calculate-score",
+          "params": { "factor": 2 }
+      }))
+      .unwrap(),
+      Script::id("calculate-score").params(params.as_object().unwrap().clone())
+    );
+  }
 }
@@ -1,5 +1,7 @@
 use serde_json::Value;
 
+use crate::search::*;
+
 /// Number of matching terms to be required
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum TermsSetMinimumShouldMatch {
@@ -18,7 +20,7 @@ pub enum TermsSetMinimumShouldMatch {
   /// For an example query using the `minimum_should_match_script` parameter,
   /// see [How to use the `minimum_should_match_script` parameter](https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-terms-set-query.html#terms-set-query-script).
   #[serde(rename = "minimum_should_match_script")]
-  Script(TermsSetScript),
+  Script(Script),
 }
 
 impl From<String> for TermsSetMinimumShouldMatch {
@@ -33,9 +35,15 @@ impl<'a> From<&'a str> for TermsSetMinimumShouldMatch {
   }
 }
 
+impl From<Script> for TermsSetMinimumShouldMatch {
+  fn from(script: Script) -> Self {
+    Self::Script(script)
+  }
+}
+
 impl From<TermsSetScript> for TermsSetMinimumShouldMatch {
   fn from(script: TermsSetScript) -> Self {
-    Self::Script(script)
+    Self::Script(script.into())
   }
 }
 
@@ -44,6 +52,8 @@ impl From<TermsSetScript> for TermsSetMinimumShouldMatch {
 ///
 /// For parameters and valid values, see
 /// [Scripting](https://www.elastic.co/guide/en/opensearch/reference/current/modules-scripting.html).
+///
+/// Shorthand for an inline [`Script`], which it is converted to.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TermsSetScript {
   source: String,
@@ -76,9 +86,20 @@ impl TermsSetScript {
     }
   }
 
-  /// Assign params
+  /// Assign params, must be an object of named parameters
   pub fn params(mut self, params: Value) -> Self {
     self.params = Some(params);
     self
   }
 }
+
+impl From<TermsSetScript> for Script {
+  fn from(value: TermsSetScript) -> Self {
+    let script = Script::source(value.source);
+
+    match value.params {
+      Some(Value::Object(params)) => script.params(params),
+      _ => script,
+    }
+  }
+}
@@ -34,3 +34,31 @@ pub enum TextQueryType {
   /// [`bool_prefix`](https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-multi-match-query.html#type-bool-prefix).
   BoolPrefix,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::util::*;
+
+  #[test]
+  fn serialization() {
+    assert_serialize(
+      [
+        TextQueryType::BestFields,
+        TextQueryType::MostFields,
+        TextQueryType::CrossFields,
+        TextQueryType::Phrase,
+        TextQueryType::PhrasePrefix,
+        TextQueryType::BoolPrefix,
+      ],
+      json!([
+        "best_fields",
+        "most_fields",
+        "cross_fields",
+        "phrase",
+        "phrase_prefix",
+        "bool_prefix"
+      ]),
+    );
+  }
+}
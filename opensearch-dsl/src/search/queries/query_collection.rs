@@ -36,6 +36,30 @@ impl QueryCollection {
       .0
       .extend(query.into_iter().map(Into::into).filter(ShouldSkip::should_keep))
   }
+
+  /// Queries in the collection, in insertion order
+  pub fn as_slice(&self) -> &[Query] {
+    &self.0
+  }
+
+  pub(crate) fn map<F>(self, f: F) -> Self
+  where
+    F: FnMut(Query) -> Query, {
+    Self(self.0.into_iter().map(f).collect())
+  }
+
+  /// Removes structurally equal queries, keeping the first occurrence of each
+  pub fn dedupe(&mut self) {
+    let mut unique: Vec<Query> = Vec::with_capacity(self.0.len());
+
+    for query in self.0.drain(..) {
+      if !unique.contains(&query) {
+        unique.push(query);
+      }
+    }
+
+    self.0 = unique;
+  }
 }
 
 #[cfg(test)]
@@ -65,6 +89,19 @@ mod tests {
     assert_eq!(queries.0.len(), 2);
   }
 
+  #[test]
+  fn dedupes_equal_queries() {
+    let mut queries = QueryCollection::default();
+
+    queries.extend([Query::term("test", 1), Query::term("test", 2), Query::term("test", 1)]);
+    queries.dedupe();
+
+    assert_eq!(
+      queries.0,
+      [Query::from(Query::term("test", 1)), Query::from(Query::term("test", 2))]
+    );
+  }
+
   #[test]
   fn skips_queries() {
     let mut queries = QueryCollection::default();
@@ -10,8 +10,14 @@ use crate::{util::*, Query};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanContainingQuery {
-  little: Box<SpanQuery>,
-  big: Box<SpanQuery>,
+  pub(crate) little: Box<SpanQuery>,
+  pub(crate) big: Box<SpanQuery>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl Query {
@@ -23,10 +29,16 @@ impl Query {
     SpanContainingQuery {
       little: Box::new(little.into()),
       big: Box::new(big.into()),
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanContainingQuery {
+  add_boost_and_name!();
+}
+
 impl ShouldSkip for SpanContainingQuery {}
 
 serialize_with_root!("span_containing": SpanContainingQuery);
@@ -20,8 +20,14 @@ use crate::{util::*, Query, SpanQuery};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanFieldMaskingQuery {
-  query: Box<SpanQuery>,
+  pub(crate) query: Box<SpanQuery>,
   field: String,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl Query {
@@ -34,10 +40,16 @@ impl Query {
     SpanFieldMaskingQuery {
       query: Box::new(query.into()),
       field: field.to_string(),
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanFieldMaskingQuery {
+  add_boost_and_name!();
+}
+
 impl ShouldSkip for SpanFieldMaskingQuery {}
 
 serialize_with_root!("span_field_masking": SpanFieldMaskingQuery);
@@ -11,8 +11,14 @@ use crate::{util::*, Query, SpanQuery};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanFirstQuery {
-  r#match: Box<SpanQuery>,
+  pub(crate) r#match: Box<SpanQuery>,
   end: u32,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl Query {
@@ -23,10 +29,16 @@ impl Query {
     SpanFirstQuery {
       r#match: Box::new(r#match.into()),
       end,
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanFirstQuery {
+  add_boost_and_name!();
+}
+
 impl ShouldSkip for SpanFirstQuery {}
 
 serialize_with_root!("span_first": SpanFirstQuery);
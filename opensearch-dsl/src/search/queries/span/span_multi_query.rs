@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::{util::*, MultiTermQuery, Query};
+use crate::{util::*, MultiTermQuery, Query, Rewrite};
 
 /// The span_multi query allows you to wrap a `multi term query` (one of
 /// [`wildcard`](crate::WildcardQuery), [`fuzzy`](crate::FuzzyQuery),
@@ -8,11 +8,26 @@ use crate::{util::*, MultiTermQuery, Query};
 /// [`regexp`](crate::RegexpQuery) query) as a [`span query`](crate::SpanQuery),
 /// so it can be nested.
 ///
+/// Only multi term queries are accepted, any other query is rejected at
+/// compile time.
+///
+/// To create span multi query:
+/// ```
+/// # use opensearch_dsl::queries::*;
+/// # let query =
+/// Query::span_multi(Query::prefix("user.id", "ki"));
+/// ```
 /// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-span-multi-term-query.html>
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanMultiQuery {
-  r#match: Box<MultiTermQuery>,
+  pub(crate) r#match: Box<MultiTermQuery>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl ShouldSkip for SpanMultiQuery {}
@@ -22,16 +37,38 @@ deserialize_with_root!("span_multi": SpanMultiQuery);
 
 impl Query {
   /// Creates an instance of [`SpanMultiQuery`]
-  #[allow(unused)]
+  ///
+  /// - `match` - Multi term query to wrap
   pub fn span_multi<Q>(r#match: Q) -> SpanMultiQuery
   where
     Q: Into<MultiTermQuery>, {
     SpanMultiQuery {
       r#match: Box::new(r#match.into()),
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanMultiQuery {
+  add_boost_and_name!();
+
+  /// Method used to rewrite the wrapped multi term query. Span queries can't
+  /// use the default `constant_score` bit set rewrite, so large expansions
+  /// may exceed the clause limit unless a `top_terms_*` method is chosen.
+  /// Has no effect on a wrapped [`range`](crate::RangeQuery) query.
+  pub fn rewrite(mut self, rewrite: Rewrite) -> Self {
+    self.r#match = Box::new(match *self.r#match {
+      MultiTermQuery::Prefix(q) => MultiTermQuery::Prefix(q.rewrite(rewrite)),
+      MultiTermQuery::Regexp(q) => MultiTermQuery::Regexp(q.rewrite(rewrite)),
+      MultiTermQuery::Wildcard(q) => MultiTermQuery::Wildcard(q.rewrite(rewrite)),
+      MultiTermQuery::Fuzzy(q) => MultiTermQuery::Fuzzy(q.rewrite(rewrite)),
+      MultiTermQuery::Range(q) => MultiTermQuery::Range(q),
+    });
+    self
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -68,4 +105,62 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_rewrite_of_wrapped_query() {
+    assert_serialize_query(
+      Query::span_multi(Query::prefix("test", "1234")).rewrite(Rewrite::TopTermsBoost(20)),
+      json!({
+          "span_multi": {
+              "match" : {
+                  "prefix": {
+                      "test": {
+                          "value": "1234",
+                          "rewrite": "top_terms_boost_20"
+                      }
+                  }
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::span_multi(Query::fuzzy("test", "kimchy")).rewrite(Rewrite::TopTermsBlendedFrequencies(5)),
+      json!({
+          "span_multi": {
+              "match" : {
+                  "fuzzy": {
+                      "test": {
+                          "value": "kimchy",
+                          "rewrite": "top_terms_blended_freqs_5"
+                      }
+                  }
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn wraps_every_multi_term_query() {
+    assert_serialize_query(
+      Query::span_multi(Query::wildcard("user.id", "ki*y")),
+      json!({ "span_multi": { "match": { "wildcard": { "user.id": { "value": "ki*y" } } } } }),
+    );
+
+    assert_serialize_query(
+      Query::span_multi(Query::fuzzy("user.id", "ki")),
+      json!({ "span_multi": { "match": { "fuzzy": { "user.id": { "value": "ki" } } } } }),
+    );
+
+    assert_serialize_query(
+      Query::span_multi(Query::regexp("user.id", "k.*y")),
+      json!({ "span_multi": { "match": { "regexp": { "user.id": { "value": "k.*y" } } } } }),
+    );
+
+    assert_serialize_query(
+      Query::span_multi(Query::range("age").gte(10)),
+      json!({ "span_multi": { "match": { "range": { "age": { "gte": 10 } } } } }),
+    );
+  }
 }
@@ -13,13 +13,19 @@ use crate::{util::*, Query, SpanQuery};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanNearQuery {
-  clauses: Vec<SpanQuery>,
+  pub(crate) clauses: Vec<SpanQuery>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   in_order: Option<bool>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   slop: Option<i32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl ShouldSkip for SpanNearQuery {
@@ -38,11 +44,15 @@ impl Query {
       clauses: clauses.into_iter().map(Into::into).collect(),
       in_order: None,
       slop: None,
+      boost: None,
+      _name: None,
     }
   }
 }
 
 impl SpanNearQuery {
+  add_boost_and_name!();
+
   /// Controls whether span matches are required to be in-order.
   pub fn in_order(mut self, in_order: bool) -> Self {
     self.in_order = Some(in_order);
@@ -57,6 +67,11 @@ impl SpanNearQuery {
     self.slop = Some(slop);
     self
   }
+
+  /// Span queries that must match near one another
+  pub fn clauses(&self) -> &[SpanQuery] {
+    &self.clauses
+  }
 }
 
 serialize_with_root!("span_near": SpanNearQuery);
@@ -106,4 +121,25 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_boost_and_name() {
+    assert_serialize_query(
+      Query::span_near([Query::span_term("title", "quick"), Query::span_term("title", "fox")])
+        .slop(2)
+        .boost(1.5)
+        .name("quick_fox"),
+      json!({
+          "span_near": {
+              "clauses": [
+                  { "span_term": { "title": { "value": "quick" } } },
+                  { "span_term": { "title": { "value": "fox" } } }
+              ],
+              "slop": 2,
+              "boost": 1.5,
+              "_name": "quick_fox"
+          }
+      }),
+    );
+  }
 }
@@ -12,6 +12,20 @@ use crate::{util::*, Query, SpanQuery};
 /// `exclude` clause is the span query whose matches must not overlap those
 /// returned.
 ///
+/// To create a span not query:
+/// ```
+/// # use opensearch_dsl::queries::*;
+/// # let query =
+/// Query::span_not(
+///   Query::span_near([
+///     Query::span_term("field1", "la"),
+///     Query::span_term("field1", "hoya"),
+///   ])
+///   .slop(0),
+///   Query::span_term("field1", "hoya"),
+/// )
+/// .dist(1);
+/// ```
 /// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-span-not-query.html>
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
@@ -19,38 +33,44 @@ pub struct SpanNotQuery {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   dist: Option<i32>,
 
-  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  exclude: Vec<SpanQuery>,
+  pub(crate) exclude: Box<SpanQuery>,
 
-  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  include: Vec<SpanQuery>,
+  pub(crate) include: Box<SpanQuery>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   post: Option<i32>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   pre: Option<i32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl Query {
   /// Creates an instance of [`SpanNotQuery`]
   pub fn span_not<T, U>(exclude: T, include: U) -> SpanNotQuery
   where
-    T: IntoIterator,
-    T::Item: Into<SpanQuery>,
-    U: IntoIterator,
-    U::Item: Into<SpanQuery>, {
+    T: Into<SpanQuery>,
+    U: Into<SpanQuery>, {
     SpanNotQuery {
-      exclude: exclude.into_iter().map(Into::into).collect(),
-      include: include.into_iter().map(Into::into).collect(),
+      exclude: Box::new(exclude.into()),
+      include: Box::new(include.into()),
       dist: None,
       post: None,
       pre: None,
+      boost: None,
+      _name: None,
     }
   }
 }
 
 impl SpanNotQuery {
+  add_boost_and_name!();
+
   /// If set the amount of tokens from within the include span can’t have
   /// overlap with the exclude span.
   ///
@@ -77,6 +97,16 @@ impl SpanNotQuery {
     self.pre = Some(pre);
     self
   }
+
+  /// Span query whose matches are filtered
+  pub fn include(&self) -> &SpanQuery {
+    &self.include
+  }
+
+  /// Span query whose matches must not overlap the included ones
+  pub fn exclude(&self) -> &SpanQuery {
+    &self.exclude
+  }
 }
 
 impl ShouldSkip for SpanNotQuery {}
@@ -91,31 +121,27 @@ mod tests {
   #[test]
   fn serialization() {
     assert_serialize_query(
-      Query::span_not([Query::span_term("foo", 1234)], [Query::span_term("bar", 4321)])
+      Query::span_not(Query::span_term("foo", 1234), Query::span_term("bar", 4321))
         .dist(1234)
         .post(4321)
         .pre(5678),
       json!({
           "span_not": {
               "dist": 1234,
-              "exclude": [
-                  {
-                      "span_term": {
-                          "foo": {
-                              "value": 1234
-                          }
+              "exclude": {
+                  "span_term": {
+                      "foo": {
+                          "value": 1234
                       }
                   }
-              ],
-              "include": [
-                  {
-                      "span_term": {
-                          "bar": {
-                              "value": 4321
-                          }
+              },
+              "include": {
+                  "span_term": {
+                      "bar": {
+                          "value": 4321
                       }
                   }
-              ],
+              },
               "post": 4321,
               "pre": 5678
           }
@@ -123,35 +149,65 @@ mod tests {
     );
 
     assert_serialize_query(
-      Query::span_not([Query::span_term("foo", 1234)], [Query::span_term("bar", 4321)])
+      Query::span_not(Query::span_term("foo", 1234), Query::span_term("bar", 4321))
         .dist(1234)
         .post(4321)
         .pre(5678),
       json!({
           "span_not": {
               "dist": 1234,
-              "exclude": [
-                  {
-                      "span_term": {
-                          "foo": {
-                              "value": 1234
-                          }
+              "exclude": {
+                  "span_term": {
+                      "foo": {
+                          "value": 1234
                       }
                   }
-              ],
-              "include": [
-                  {
-                      "span_term": {
-                          "bar": {
-                              "value": 4321
-                          }
+              },
+              "include": {
+                  "span_term": {
+                      "bar": {
+                          "value": 4321
                       }
                   }
-              ],
+              },
               "post": 4321,
               "pre": 5678
           }
       }),
     );
   }
+
+  #[test]
+  fn serializes_dist_with_single_clauses() {
+    let query = Query::span_not(
+      Query::span_near([Query::span_term("field1", "la"), Query::span_term("field1", "hoya")])
+        .slop(0)
+        .in_order(true),
+      Query::span_term("field1", "hoya"),
+    )
+    .dist(1);
+
+    assert_eq!(query.include(), &SpanQuery::from(Query::span_term("field1", "hoya")));
+    assert_serialize_query(
+      query,
+      json!({
+          "span_not": {
+              "dist": 1,
+              "exclude": {
+                  "span_near": {
+                      "clauses": [
+                          { "span_term": { "field1": { "value": "la" } } },
+                          { "span_term": { "field1": { "value": "hoya" } } }
+                      ],
+                      "in_order": true,
+                      "slop": 0
+                  }
+              },
+              "include": {
+                  "span_term": { "field1": { "value": "hoya" } }
+              }
+          }
+      }),
+    );
+  }
 }
@@ -12,7 +12,13 @@ use crate::{util::*, Query};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanOrQuery {
-  clauses: Vec<SpanQuery>,
+  pub(crate) clauses: Vec<SpanQuery>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl ShouldSkip for SpanOrQuery {}
@@ -25,10 +31,21 @@ impl Query {
     T::Item: Into<SpanQuery>, {
     SpanOrQuery {
       clauses: clauses.into_iter().map(Into::into).collect(),
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanOrQuery {
+  add_boost_and_name!();
+
+  /// Span queries any of which must match
+  pub fn clauses(&self) -> &[SpanQuery] {
+    &self.clauses
+  }
+}
+
 serialize_with_root!("span_or": SpanOrQuery);
 deserialize_with_root!("span_or": SpanOrQuery);
 
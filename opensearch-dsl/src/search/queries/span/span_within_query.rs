@@ -13,8 +13,14 @@ use crate::{util::*, Query};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct SpanWithinQuery {
-  big: Box<SpanQuery>,
-  little: Box<SpanQuery>,
+  pub(crate) big: Box<SpanQuery>,
+  pub(crate) little: Box<SpanQuery>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  boost: Option<f32>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _name: Option<String>,
 }
 
 impl Query {
@@ -26,10 +32,16 @@ impl Query {
     SpanWithinQuery {
       little: Box::new(little.into()),
       big: Box::new(big.into()),
+      boost: None,
+      _name: None,
     }
   }
 }
 
+impl SpanWithinQuery {
+  add_boost_and_name!();
+}
+
 impl ShouldSkip for SpanWithinQuery {}
 
 serialize_with_root!("span_within": SpanWithinQuery);
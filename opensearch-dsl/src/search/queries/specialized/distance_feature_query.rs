@@ -77,7 +77,7 @@ impl Origin for GeoLocation {
 /// Query::distance_feature(
 ///   "test",
 ///   GeoLocation::new(-71.34, 40.12),
-///   Distance::Kilometers(15),
+///   Distance::Kilometers(15.0),
 /// )
 /// .boost(1.5)
 /// .name("test");
@@ -90,7 +90,7 @@ impl Origin for GeoLocation {
 /// # use opensearch_dsl::queries::*;
 /// # use opensearch_dsl::queries::params::*;
 /// # let query =
-/// Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Distance::Kilometers(15))
+/// Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Distance::Kilometers(15.0))
 ///     .boost(1.5)
 ///     .name("test");
 /// ```
@@ -101,11 +101,11 @@ impl Origin for GeoLocation {
 pub struct DistanceFeatureQuery<O>
 where
   O: Origin + DeserializeOwned, {
-  field: String,
+  pub(crate) field: String,
 
   origin: O,
 
-  pivot: <O as Origin>::Pivot,
+  pub(crate) pivot: <O as Origin>::Pivot,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
@@ -220,24 +220,24 @@ mod tests {
       }),
     );
     assert_serialize_query(
-      Query::distance_feature("test", GeoLocation::new(12.0, 13.0), Distance::Kilometers(15)),
+      Query::distance_feature("test", GeoLocation::new(12.0, 13.0), Distance::Kilometers(15.0)),
       json!({
           "distance_feature": {
               "field": "test",
-              "origin": [13.0, 12.0],
+              "origin": { "lat": 12.0, "lon": 13.0 },
               "pivot": "15km",
           }
       }),
     );
 
     assert_serialize_query(
-      Query::distance_feature("test", GeoLocation::new(12.0, 13.0), Distance::Kilometers(15))
+      Query::distance_feature("test", GeoLocation::new(12.0, 13.0), Distance::Kilometers(15.0))
         .boost(2)
         .name("test"),
       json!({
           "distance_feature": {
               "field": "test",
-              "origin": [13.0, 12.0],
+              "origin": { "lat": 12.0, "lon": 13.0 },
               "pivot": "15km",
               "boost": 2.0,
               "_name": "test",
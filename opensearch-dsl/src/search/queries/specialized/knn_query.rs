@@ -18,28 +18,33 @@ use crate::{search::*, util::*};
 ///
 /// To create a knn query:
 /// ```
-/// # use elasticsearch_dsl::queries::*;
+/// # use opensearch_dsl::queries::*;
 /// # let query =
-/// Query::knn("test", vec![1.0, 2.0, 3.0]);
+/// Query::knn("test", vec![1.0, 2.0, 3.0])
+///   .filter(Query::term("genre", "drama"))
+///   .boost(2.0);
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-knn-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(remote = "Self")]
 pub struct KnnQuery {
-  field: String,
+  pub(crate) field: String,
 
   #[serde(default)]
-  query_vector: Vec<f32>,
+  pub(crate) query_vector: Vec<f32>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   num_candidates: Option<u32>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  filter: Option<Box<Query>>,
+  pub(crate) filter: Option<Box<Query>>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   similarity: Option<f32>,
 
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  method_parameters: KnnMethodParameters,
+
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
 
@@ -83,10 +88,46 @@ impl KnnQuery {
     self.similarity = Some(similarity);
     self
   }
+
+  /// Engine specific parameters applied to this query only, overriding the
+  /// ones configured on the index
+  pub fn method_parameters(mut self, method_parameters: KnnMethodParameters) -> Self {
+    self.method_parameters = method_parameters;
+    self
+  }
 }
 
 impl ShouldSkip for KnnQuery {}
 
+/// Query time parameters of the approximate kNN search method
+///
+/// <https://opensearch.org/docs/latest/search-plugins/knn/approximate-knn/#additional-query-parameters>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnnMethodParameters {
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  ef_search: Option<u32>,
+}
+
+impl KnnMethodParameters {
+  /// Creates an empty instance of [`KnnMethodParameters`]
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// The number of vectors to examine while searching an HNSW graph. Larger
+  /// values improve recall at the cost of latency.
+  pub fn ef_search(mut self, ef_search: u32) -> Self {
+    self.ef_search = Some(ef_search);
+    self
+  }
+}
+
+impl ShouldSkip for KnnMethodParameters {
+  fn should_skip(&self) -> bool {
+    self.ef_search.is_none()
+  }
+}
+
 serialize_with_root!("knn": KnnQuery);
 deserialize_with_root!("knn": KnnQuery);
 
@@ -106,6 +147,7 @@ impl Query {
       num_candidates: None,
       filter: None,
       similarity: None,
+      method_parameters: Default::default(),
       boost: None,
       _name: None,
     }
@@ -154,4 +196,54 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_filter_only_when_set() {
+    assert_serialize_query(
+      Query::knn("embedding", vec![0.5, 0.25])
+        .filter(Query::bool().filter(Query::term("genre", "drama")))
+        .boost(1.5),
+      json!({
+          "knn": {
+              "field": "embedding",
+              "query_vector": [0.5, 0.25],
+              "filter": {
+                  "bool": {
+                      "filter": [
+                          { "term": { "genre": { "value": "drama" } } }
+                      ]
+                  }
+              },
+              "boost": 1.5
+          }
+      }),
+    );
+
+    let value = serde_json::to_value(Query::from(Query::knn("embedding", vec![0.5, 0.25]).boost(1.5))).unwrap();
+    assert!(value["knn"].get("filter").is_none());
+  }
+
+  #[test]
+  fn serializes_method_parameters_only_when_set() {
+    assert_serialize_query(
+      Query::knn("embedding", vec![0.5, 0.25]).method_parameters(KnnMethodParameters::new()),
+      json!({
+          "knn": {
+              "field": "embedding",
+              "query_vector": [0.5, 0.25]
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::knn("embedding", vec![0.5, 0.25]).method_parameters(KnnMethodParameters::new().ef_search(100)),
+      json!({
+          "knn": {
+              "field": "embedding",
+              "query_vector": [0.5, 0.25],
+              "method_parameters": { "ef_search": 100 }
+          }
+      }),
+    );
+  }
 }
@@ -39,12 +39,13 @@ use crate::{search::*, util::*};
 /// Query::more_like_this(["test"]).fields(["title"]);
 /// ```
 /// To create a `more_like_this` query with string and document id fields on
-/// title and description with optional fields: ```
+/// title and description with optional fields:
+/// ```
 /// # use opensearch_dsl::queries::*;
 /// # use opensearch_dsl::queries::params::*;
 /// # let query =
-/// Query::more_like_this([Like::from(Document::new("123")),
-/// Like::from("test")])   .fields(["title", "description"])
+/// Query::more_like_this([Like::from(Document::new("123")), Like::from("test")])
+///   .fields(["title", "description"])
 ///   .min_term_freq(1)
 ///   .max_query_terms(12)
 ///   .boost(1.2)
@@ -87,7 +88,7 @@ pub struct MoreLikeThisQuery {
   analyzer: Option<String>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  minimum_should_match: Option<String>,
+  minimum_should_match: Option<MinimumShouldMatch>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   fail_on_unsupported_field: Option<bool>,
@@ -115,6 +116,10 @@ pub enum Like {
   /// Struct to describe opensearch document which will be used in `like` field
   /// array
   Document(Document),
+
+  /// Artificial document not present in the index which will be used in
+  /// `like` field array
+  Artificial(ArtificialDocument),
 }
 
 impl From<String> for Like {
@@ -135,6 +140,12 @@ impl From<Document> for Like {
   }
 }
 
+impl From<ArtificialDocument> for Like {
+  fn from(value: ArtificialDocument) -> Self {
+    Self::Artificial(value)
+  }
+}
+
 /// One of `like` and `unlike` types which has like document structure
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Document {
@@ -204,6 +215,38 @@ impl Document {
   }
 }
 
+/// One of `like` and `unlike` types which provides the document inline,
+/// analyzed as if it was indexed
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ArtificialDocument {
+  doc: serde_json::Value,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  _index: Option<String>,
+}
+
+impl ArtificialDocument {
+  /// Creates an instance of [ArtificialDocument](ArtificialDocument)
+  ///
+  /// - `doc` - document body, analyzed with the mapping of the target index
+  pub fn new<T>(doc: T) -> Self
+  where
+    T: Into<serde_json::Value>, {
+    Self {
+      doc: doc.into(),
+      _index: None,
+    }
+  }
+
+  /// The index whose mapping is used to analyze the document.
+  pub fn index<T>(mut self, index: T) -> Self
+  where
+    T: ToString, {
+    self._index = Some(index.to_string());
+    self
+  }
+}
+
 impl Query {
   /// Creates an instance of [`MoreLikeThisQuery`]
   ///
@@ -333,8 +376,8 @@ impl MoreLikeThisQuery {
   /// should match`. (Defaults to "30%").
   pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
   where
-    T: ToString, {
-    self.minimum_should_match = Some(minimum_should_match.to_string());
+    T: Into<MinimumShouldMatch>, {
+    self.minimum_should_match = Some(minimum_should_match.into());
     self
   }
 
@@ -499,4 +542,38 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_mixed_like_items() {
+    assert_serialize_query(
+      Query::more_like_this([
+        Like::from("Once upon a time"),
+        Like::from(Document::new("1").index("imdb")),
+        Like::from(ArtificialDocument::new(json!({ "plot": "A princess and a dragon" })).index("imdb")),
+      ])
+      .unlike([Document::new("2").index("imdb")])
+      .fields(["title", "plot"])
+      .min_term_freq(1)
+      .min_doc_freq(2)
+      .max_query_terms(12)
+      .minimum_should_match("30%"),
+      json!({
+          "more_like_this": {
+              "fields": ["title", "plot"],
+              "like": [
+                  "Once upon a time",
+                  { "_id": "1", "_index": "imdb" },
+                  { "doc": { "plot": "A princess and a dragon" }, "_index": "imdb" }
+              ],
+              "unlike": [
+                  { "_id": "2", "_index": "imdb" }
+              ],
+              "min_term_freq": 1,
+              "max_query_terms": 12,
+              "min_doc_freq": 2,
+              "minimum_should_match": "30%"
+          }
+      }),
+    );
+  }
 }
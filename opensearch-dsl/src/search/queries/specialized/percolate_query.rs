@@ -186,4 +186,27 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_multiple_documents() {
+    let query = Query::percolate(
+      "query",
+      [json!({ "message": "bonsai tree" }), json!({ "message": "new tree" })],
+    )
+    .name("trees");
+
+    assert_serialize_query(
+      query,
+      json!({
+          "percolate": {
+              "field": "query",
+              "name": "trees",
+              "documents": [
+                  { "message": "bonsai tree" },
+                  { "message": "new tree" }
+              ]
+          }
+      }),
+    );
+  }
 }
@@ -1,9 +1,13 @@
 use crate::{search::*, util::*};
 
-/// The most simple query, which matches all documents, giving them all a
-/// `_score` of `1.0`.
+/// Promotes selected documents to rank higher than those matching a given
+/// `organic` query.
 ///
-/// To create match_all query:
+/// The pinned documents are identified either by their `ids` or, when they
+/// live in different indices, by `docs` index and id pairs. Only one of the
+/// two forms can be used, which [`PinnedQueryValues`] enforces.
+///
+/// To create pinned query:
 /// ```
 /// # use opensearch_dsl::queries::*;
 /// # use opensearch_dsl::queries::params::*;
@@ -12,7 +16,7 @@ use crate::{search::*, util::*};
 ///   .boost(2)
 ///   .name("matches_everything");
 /// ```
-/// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-match-all-query.html>
+/// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-pinned-query.html>
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct PinnedQuery {
@@ -21,7 +25,7 @@ pub struct PinnedQuery {
 
   /// Any choice of query used to rank documents which will be ranked below
   /// the "pinned" documents.
-  organic: Box<Query>,
+  pub(crate) organic: Box<Query>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
@@ -19,7 +19,7 @@ use crate::{search::*, util::*};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct ScriptScoreQuery {
-  query: Box<Query>,
+  pub(crate) query: Box<Query>,
 
   script: Script,
 
@@ -54,6 +54,15 @@ impl Query {
 
 impl ScriptScoreQuery {
   add_boost_and_name!();
+
+  /// Documents with a score lower than this floating point number are
+  /// excluded from the search results
+  pub fn min_score<T>(mut self, min_score: T) -> Self
+  where
+    T: Into<f32>, {
+    self.min_score = Some(min_score.into());
+    self
+  }
 }
 
 impl ShouldSkip for ScriptScoreQuery {}
@@ -86,4 +95,30 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_min_score_next_to_script() {
+    let params = json!({ "origin": "2020-01-01", "scale": "10d" });
+
+    assert_serialize_query(
+      Query::script_score(
+        Query::term("status", "published"),
+        Script::source("decayDateGauss(params.origin, params.scale, '0d', 0.5, doc['date'].value)")
+          .lang(ScriptLang::Painless)
+          .params(params.as_object().unwrap().clone()),
+      )
+      .min_score(0.5_f32),
+      json!({
+          "script_score": {
+              "query": { "term": { "status": { "value": "published" } } },
+              "script": {
+                  "source": "decayDateGauss(params.origin, params.scale, '0d', 0.5, doc['date'].value)",
+                  "lang": "painless",
+                  "params": { "origin": "2020-01-01", "scale": "10d" }
+              },
+              "min_score": 0.5
+          }
+      }),
+    );
+  }
 }
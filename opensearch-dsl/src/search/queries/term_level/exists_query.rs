@@ -55,6 +55,18 @@ impl Query {
 
 impl ExistsQuery {
   add_boost_and_name!();
+
+  /// Wraps the query in the `must_not` clause of a [`BoolQuery`], matching
+  /// documents that don't contain an indexed value for the field
+  ///
+  /// ```
+  /// # use opensearch_dsl::queries::*;
+  /// # let query =
+  /// Query::exists("user").negate();
+  /// ```
+  pub fn negate(self) -> BoolQuery {
+    Query::bool().must_not(self)
+  }
 }
 
 impl ShouldSkip for ExistsQuery {}
@@ -88,4 +100,27 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn negate_wraps_in_must_not() {
+    assert_serialize_query(
+      Query::exists("user").name("has_user").negate(),
+      json!({
+          "bool": {
+              "must_not": [
+                  { "exists": { "field": "user", "_name": "has_user" } }
+              ]
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::exists("user").boost(2).name("has_user");
+    let json = json!({ "exists": { "field": "user", "boost": 2.0, "_name": "has_user" } });
+
+    assert_eq!(serde_json::from_value::<ExistsQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
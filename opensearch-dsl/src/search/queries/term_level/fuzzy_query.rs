@@ -184,4 +184,24 @@ mod tests {
       json!({ "bool": {} }),
     )
   }
+
+  #[test]
+  fn serializes_top_terms_rewrite_without_transpositions() {
+    assert_serialize_query(
+      Query::fuzzy("user.id", "ki")
+        .fuzziness(Fuzziness::Range(3, 6))
+        .transpositions(false)
+        .rewrite(Rewrite::TopTerms(10)),
+      json!({
+          "fuzzy": {
+              "user.id": {
+                  "value": "ki",
+                  "fuzziness": "AUTO:3,6",
+                  "transpositions": false,
+                  "rewrite": "top_terms_10"
+              }
+          }
+      }),
+    );
+  }
 }
@@ -11,6 +11,12 @@ use crate::{search::*, util::*, Set};
 /// # let query =
 /// Query::ids(vec!["2"]);
 /// ```
+/// or by collecting an iterator of IDs:
+/// ```
+/// # use opensearch_dsl::queries::*;
+/// # let query: IdsQuery =
+/// (1..=3).map(|id| format!("user-{id}")).collect();
+/// ```
 /// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-ids-query.html>
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
@@ -44,6 +50,22 @@ impl Query {
 
 impl IdsQuery {
   add_boost_and_name!();
+
+  /// Document IDs the query matches, in sorted order
+  pub fn values(&self) -> &Set<String> {
+    &self.values
+  }
+}
+
+impl<T> FromIterator<T> for IdsQuery
+where
+  T: ToString,
+{
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = T>, {
+    Query::ids(iter)
+  }
 }
 
 impl ShouldSkip for IdsQuery {
@@ -81,4 +103,13 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn collects_from_iterator() {
+    let query: IdsQuery = ["3", "1", "2", "1"].iter().map(|id| id.to_string()).collect();
+
+    assert_eq!(query.values().iter().collect::<Vec<_>>(), ["1", "2", "3"]);
+    assert_eq!(query, IdsQuery::from_iter(1..=3));
+    assert_serialize_query(query, json!({ "ids": { "values": ["1", "2", "3"] } }));
+  }
 }
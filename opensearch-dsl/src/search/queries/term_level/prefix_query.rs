@@ -22,7 +22,7 @@ use crate::{search::*, util::*};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct PrefixQuery {
-  #[serde(skip_serializing)]
+  #[serde(skip)]
   field: String,
 
   value: Option<Term>,
@@ -131,4 +131,26 @@ mod tests {
       json!({ "bool": {} }),
     )
   }
+
+  #[test]
+  fn serializes_case_insensitive_only_when_set() {
+    assert_serialize_query(
+      Query::prefix("user.id", "Ki"),
+      json!({ "prefix": { "user.id": { "value": "Ki" } } }),
+    );
+
+    assert_serialize_query(
+      Query::prefix("user.id", "Ki").case_insensitive(false),
+      json!({ "prefix": { "user.id": { "value": "Ki", "case_insensitive": false } } }),
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::prefix("user", "ki").case_insensitive(true);
+    let json = json!({ "prefix": { "user": { "value": "ki", "case_insensitive": true } } });
+
+    assert_eq!(serde_json::from_value::<PrefixQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
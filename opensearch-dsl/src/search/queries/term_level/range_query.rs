@@ -20,25 +20,25 @@ use crate::{search::*, util::*};
 #[serde(remote = "Self")]
 pub struct RangeQuery {
   #[serde(skip)]
-  field: String,
+  pub(crate) field: String,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  gt: Option<Term>,
+  pub(crate) gt: Option<Term>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  gte: Option<Term>,
+  pub(crate) gte: Option<Term>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  lt: Option<Term>,
+  pub(crate) lt: Option<Term>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  lte: Option<Term>,
+  pub(crate) lte: Option<Term>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   format: Option<String>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  relation: Option<RangeRelation>,
+  pub(crate) relation: Option<RangeRelation>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   time_zone: Option<String>,
@@ -205,7 +205,7 @@ mod tests {
                   "gte": 2,
                   "lt": 3,
                   "lte": 4,
-                  "relation": "WITHIN",
+                  "relation": "within",
                   "boost": 2.0,
                   "_name": "range_query_test"
               }
@@ -233,7 +233,7 @@ mod tests {
                   "lte": "2014-11-28T12:00:04Z",
                   "format": "yyyy-MM-dd",
                   "time_zone": "UTC",
-                  "relation": "CONTAINS",
+                  "relation": "contains",
                   "boost": 2.0,
                   "_name": "range_query_test"
               }
@@ -241,4 +241,58 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn relation_only_serialized_when_set() {
+    assert_serialize_query(
+      Query::range("test_range_field").gte(10).lte(20),
+      json!({
+          "range": {
+              "test_range_field": {
+                  "gte": 10,
+                  "lte": 20
+              }
+          }
+      }),
+    );
+
+    assert_serialize_query(
+      Query::range("test_range_field")
+        .gte(10)
+        .lte(20)
+        .relation(RangeRelation::Within),
+      json!({
+          "range": {
+              "test_range_field": {
+                  "gte": 10,
+                  "lte": 20,
+                  "relation": "within"
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn relation_deserializes_either_case() {
+    let relations: Vec<RangeRelation> = serde_json::from_value(json!(["intersects", "CONTAINS", "within"])).unwrap();
+
+    assert_eq!(
+      relations,
+      [
+        RangeRelation::Intersects,
+        RangeRelation::Contains,
+        RangeRelation::Within
+      ]
+    );
+  }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::range("age").gte(10).lt(20);
+    let json = json!({ "range": { "age": { "gte": 10, "lt": 20 } } });
+
+    assert_eq!(serde_json::from_value::<RangeQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
@@ -28,7 +28,8 @@ pub struct RegexpQuery {
   #[serde(
     default,
     skip_serializing_if = "ShouldSkip::should_skip",
-    serialize_with = "join_with_pipe"
+    serialize_with = "join_with_pipe",
+    deserialize_with = "split_with_pipe"
   )]
   flags: Vec<RegexpFlag>,
 
@@ -171,4 +172,22 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_case_insensitive_with_combined_flags() {
+    assert_serialize_query(
+      Query::regexp("user.id", "k.*y")
+        .flags([RegexpFlag::Intersection, RegexpFlag::Anystring])
+        .case_insensitive(true),
+      json!({
+          "regexp": {
+              "user.id": {
+                  "value": "k.*y",
+                  "flags": "INTERSECTION|ANYSTRING",
+                  "case_insensitive": true
+              }
+          }
+      }),
+    );
+  }
 }
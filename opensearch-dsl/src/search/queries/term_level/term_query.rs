@@ -105,4 +105,13 @@ mod tests {
       json!({ "bool": {} }),
     )
   }
+
+  #[test]
+  fn deserialization() {
+    let query = Query::term("user", "kimchy").boost(2).name("test");
+    let json = json!({ "term": { "user": { "value": "kimchy", "boost": 2.0, "_name": "test" } } });
+
+    assert_eq!(serde_json::from_value::<TermQuery>(json.clone()).unwrap(), query);
+    assert_eq!(serde_json::from_value::<Query>(json).unwrap(), Query::from(query));
+  }
 }
@@ -34,7 +34,7 @@ pub struct TermsLookupQuery {
   field: String,
 
   #[serde(skip)]
-  terms_lookup: TermsLookup,
+  pub(crate) terms_lookup: TermsLookup,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   boost: Option<f32>,
@@ -44,10 +44,10 @@ pub struct TermsLookupQuery {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
-struct TermsLookup {
-  index: String,
-  id: String,
-  path: String,
+pub(crate) struct TermsLookup {
+  pub(crate) index: String,
+  pub(crate) id: String,
+  pub(crate) path: String,
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   routing: Option<String>,
 }
@@ -247,4 +247,21 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_documented_lookup_with_routing() {
+    assert_serialize_query(
+      Query::terms_lookup("user.id", "users", "2", "followers").routing("r1"),
+      json!({
+          "terms": {
+              "user.id": {
+                  "index": "users",
+                  "id": "2",
+                  "path": "followers",
+                  "routing": "r1"
+              }
+          }
+      }),
+    );
+  }
 }
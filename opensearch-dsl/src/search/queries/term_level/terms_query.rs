@@ -202,6 +202,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn preserves_value_types() {
+    let ids: Vec<i64> = vec![1, 2, 3_000_000_000];
+    assert_serialize_query(
+      Query::terms("id", ids),
+      json!({ "terms": { "id": [1, 2, 3_000_000_000i64] } }),
+    );
+
+    let users: Vec<String> = vec!["kimchy".into(), "opensearch".into()];
+    assert_serialize_query(
+      Query::terms("user.id", users).boost(1.5),
+      json!({ "terms": { "user.id": ["kimchy", "opensearch"], "boost": 1.5 } }),
+    );
+
+    assert_serialize_query(
+      Query::terms("tags", vec![json!(1), json!("1"), json!(true)]),
+      json!({ "terms": { "tags": [1, "1", true] } }),
+    );
+  }
+
   #[test]
   fn should_skip_when_there_are_no_values() {
     let values: Vec<i32> = Vec::new();
@@ -21,10 +21,10 @@ use crate::{search::*, util::*};
 /// # use opensearch_dsl::queries::params::*;
 /// # let query =
 /// Query::terms_set(
-///     "test",
-///     [123],
-///     TermsSetScript::new("Math.min(params.num_terms_sets, doc['required_matches'].value)")
-///         .params(serde_json::json!({"num_terms_sets": 2}))
+///   "test",
+///   [123],
+///   Script::source("Math.min(params.num_terms_sets, doc['required_matches'].value)")
+///     .param("num_terms_sets", 2),
 /// );
 /// ```
 /// <https://www.elastic.co/guide/en/opensearch/reference/current/query-dsl-terms-set-query.html>
@@ -50,9 +50,9 @@ impl Query {
   /// Creates an instance of [`TermsSetQuery`]
   ///
   /// - `field` - Field you wish to search.
-  /// - `value` - TermsSet you wish to find in the provided field.
-  /// To return a document, the terms_set must exactly match the field value,
-  /// including whitespace and capitalization.
+  /// - `terms` - Terms you wish to find in the provided field.
+  /// - `minimum_should_match` - Either the name of a numeric field or a
+  ///   [`Script`] computing the number of terms required to match.
   pub fn terms_set<S, T, U>(field: S, terms: T, minimum_should_match: U) -> TermsSetQuery
   where
     S: ToString,
@@ -125,4 +125,31 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_shared_script() {
+    assert_serialize_query(
+      Query::terms_set(
+        "tags",
+        ["rust", "search"],
+        Script::source("Math.min(params.num_terms, doc['required_matches'].value)")
+          .lang(ScriptLang::Painless)
+          .param("num_terms", 2),
+      ),
+      json!({
+          "terms_set": {
+              "tags": {
+                  "terms": ["rust", "search"],
+                  "minimum_should_match_script": {
+                      "source": "Math.min(params.num_terms, doc['required_matches'].value)",
+                      "lang": "painless",
+                      "params": {
+                          "num_terms": 2
+                      }
+                  }
+              }
+          }
+      }),
+    );
+  }
 }
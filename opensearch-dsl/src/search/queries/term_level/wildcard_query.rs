@@ -20,7 +20,7 @@ use crate::{search::*, util::*};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(remote = "Self")]
 pub struct WildcardQuery {
-  #[serde(skip_serializing)]
+  #[serde(skip)]
   field: String,
 
   value: Option<Term>,
@@ -129,4 +129,19 @@ mod tests {
       json!({ "bool": {} }),
     )
   }
+
+  #[test]
+  fn serializes_case_insensitive_under_field() {
+    assert_serialize_query(
+      Query::wildcard("user.id", "KI*Y").case_insensitive(true),
+      json!({
+          "wildcard": {
+              "user.id": {
+                  "value": "KI*Y",
+                  "case_insensitive": true
+              }
+          }
+      }),
+    );
+  }
 }
@@ -0,0 +1,297 @@
+use crate::search::*;
+
+/// Structural problem found by [`Query::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+  /// Name of the offending query, e.g. `distance_feature`
+  pub query: &'static str,
+
+  /// Name of the offending field of that query
+  pub field: &'static str,
+
+  /// Description of the problem
+  pub message: String,
+}
+
+impl ValidationError {
+  fn new<T>(query: &'static str, field: &'static str, message: T) -> Self
+  where
+    T: ToString, {
+    Self {
+      query,
+      field,
+      message: message.to_string(),
+    }
+  }
+}
+
+impl std::fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "invalid `{}` query, `{}` {}", self.query, self.field, self.message)
+  }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl Query {
+  /// Checks the structural invariants of the query and all of its clauses
+  /// that the builders can't enforce at compile time, such as mutually
+  /// exclusive bounds of a `range` query or a zero `pivot` of a
+  /// `distance_feature` query
+  ///
+  /// Returns the first problem found, in the order clauses serialize.
+  ///
+  /// ```
+  /// # use opensearch_dsl::*;
+  /// let query = Query::from(Query::range("age").gt(10).gte(10));
+  ///
+  /// assert_eq!(
+  ///   query.validate().unwrap_err().to_string(),
+  ///   "invalid `range` query, `gt` can't be combined with `gte`"
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), ValidationError> {
+    let mut visitor = ValidationVisitor::default();
+    self.visit(&mut visitor);
+
+    match visitor.error {
+      Some(error) => Err(error),
+      None => Ok(()),
+    }
+  }
+}
+
+#[derive(Default)]
+struct ValidationVisitor {
+  error: Option<ValidationError>,
+}
+
+impl ValidationVisitor {
+  fn check(&mut self, result: Result<(), ValidationError>) {
+    if self.error.is_none() {
+      self.error = result.err();
+    }
+  }
+}
+
+impl QueryVisitor for ValidationVisitor {
+  fn visit_query(&mut self, query: &Query) {
+    let result = match query {
+      Query::Range(q) => validate_range(q),
+      Query::DistanceFeatureDate(q) => validate_distance_feature(&q.field, time_value(&q.pivot) == 0),
+      Query::DistanceFeatureGeo(q) => validate_distance_feature(&q.field, distance_value(&q.pivot) <= 0.0),
+      Query::Knn(q) => validate_knn(q),
+      Query::TermsLookup(q) => validate_terms_lookup(q),
+      _ => Ok(()),
+    };
+
+    self.check(result);
+  }
+
+  fn visit_multi_term_query(&mut self, query: &MultiTermQuery) {
+    if let MultiTermQuery::Range(q) = query {
+      self.check(validate_range(q));
+    }
+  }
+}
+
+fn validate_range(query: &RangeQuery) -> Result<(), ValidationError> {
+  if query.field.is_empty() {
+    return Err(ValidationError::new("range", "field", "must not be empty"));
+  }
+
+  if query.gt.is_some() && query.gte.is_some() {
+    return Err(ValidationError::new("range", "gt", "can't be combined with `gte`"));
+  }
+
+  if query.lt.is_some() && query.lte.is_some() {
+    return Err(ValidationError::new("range", "lt", "can't be combined with `lte`"));
+  }
+
+  let unbounded = query.gt.is_none() && query.gte.is_none() && query.lt.is_none() && query.lte.is_none();
+  if query.relation.is_some() && unbounded {
+    return Err(ValidationError::new(
+      "range",
+      "relation",
+      "requires a `gt`, `gte`, `lt` or `lte` bound",
+    ));
+  }
+
+  Ok(())
+}
+
+fn validate_distance_feature(field: &str, zero_pivot: bool) -> Result<(), ValidationError> {
+  if field.is_empty() {
+    return Err(ValidationError::new("distance_feature", "field", "must not be empty"));
+  }
+
+  if zero_pivot {
+    return Err(ValidationError::new(
+      "distance_feature",
+      "pivot",
+      "must be greater than zero",
+    ));
+  }
+
+  Ok(())
+}
+
+fn validate_knn(query: &KnnQuery) -> Result<(), ValidationError> {
+  if query.field.is_empty() {
+    return Err(ValidationError::new("knn", "field", "must not be empty"));
+  }
+
+  if query.query_vector.is_empty() {
+    return Err(ValidationError::new("knn", "query_vector", "must not be empty"));
+  }
+
+  Ok(())
+}
+
+fn validate_terms_lookup(query: &TermsLookupQuery) -> Result<(), ValidationError> {
+  let lookup = &query.terms_lookup;
+
+  for (field, value) in [("index", &lookup.index), ("id", &lookup.id), ("path", &lookup.path)] {
+    if value.is_empty() {
+      return Err(ValidationError::new("terms", field, "must not be empty"));
+    }
+  }
+
+  Ok(())
+}
+
+fn time_value(time: &Time) -> u64 {
+  match *time {
+    Time::Days(u)
+    | Time::Hours(u)
+    | Time::Minutes(u)
+    | Time::Seconds(u)
+    | Time::Milliseconds(u)
+    | Time::Microseconds(u)
+    | Time::Nanoseconds(u) => u,
+  }
+}
+
+fn distance_value(distance: &Distance) -> f64 {
+  match *distance {
+    Distance::Miles(u)
+    | Distance::Yards(u)
+    | Distance::Feet(u)
+    | Distance::Inches(u)
+    | Distance::Kilometers(u)
+    | Distance::Meters(u)
+    | Distance::Centimeter(u)
+    | Distance::Millimeters(u)
+    | Distance::NauticalMiles(u) => u,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::prelude::*;
+
+  use super::*;
+
+  #[test]
+  fn accepts_valid_query() {
+    let query = Query::from(
+      Query::bool()
+        .must(Query::range("age").gte(10).lt(20))
+        .should(Query::distance_feature(
+          "date",
+          Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).single().unwrap(),
+          Time::Days(7),
+        ))
+        .filter(Query::knn("vector", vec![1.0, 2.0])),
+    );
+
+    assert_eq!(query.validate(), Ok(()));
+  }
+
+  #[test]
+  fn rejects_distance_feature_without_pivot() {
+    let query = Query::from(
+      Query::bool()
+        .must(Query::term("user", "kimchy"))
+        .should(Query::distance_feature(
+          "location",
+          GeoLocation::new(40.12, -71.34),
+          Distance::Kilometers(0.0),
+        )),
+    );
+
+    let error = query.validate().unwrap_err();
+
+    assert_eq!(error.query, "distance_feature");
+    assert_eq!(error.field, "pivot");
+    assert_eq!(
+      error.to_string(),
+      "invalid `distance_feature` query, `pivot` must be greater than zero"
+    );
+  }
+
+  #[test]
+  fn rejects_overlapping_range_bounds_in_span_multi() {
+    let query = Query::from(Query::span_multi(Query::range("age").lt(20).lte(20)));
+
+    assert_eq!(
+      query.validate(),
+      Err(ValidationError::new("range", "lt", "can't be combined with `lte`"))
+    );
+  }
+
+  #[test]
+  fn rejects_range_relation_without_bounds() {
+    let query = Query::from(Query::range("period").relation(RangeRelation::Within));
+
+    assert_eq!(
+      query.validate(),
+      Err(ValidationError::new(
+        "range",
+        "relation",
+        "requires a `gt`, `gte`, `lt` or `lte` bound"
+      ))
+    );
+    assert_eq!(
+      Query::from(Query::range("period").gte(10).relation(RangeRelation::Within)).validate(),
+      Ok(())
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_range_in_pinned_organic_query() {
+    let query = Query::from(Query::pinned(
+      PinnedQueryValues::ids([1]),
+      Query::bool().must(Query::range("age").gt(10).gte(10)),
+    ));
+
+    assert_eq!(
+      query.validate(),
+      Err(ValidationError::new("range", "gt", "can't be combined with `gte`"))
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_knn_in_function_score_filter() {
+    let query = Query::from(
+      Query::function_score()
+        .query(Query::match_all())
+        .function(Weight::new(2.0).filter(Query::knn("vector", vec![]))),
+    );
+
+    assert_eq!(
+      query.validate(),
+      Err(ValidationError::new("knn", "query_vector", "must not be empty"))
+    );
+  }
+
+  #[test]
+  fn rejects_terms_lookup_without_path() {
+    let query = Query::from(Query::terms_lookup("user.id", "users", "2", ""));
+
+    assert_eq!(
+      query.validate(),
+      Err(ValidationError::new("terms", "path", "must not be empty"))
+    );
+  }
+}
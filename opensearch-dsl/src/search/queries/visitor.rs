@@ -0,0 +1,488 @@
+use crate::search::*;
+
+/// Read only traversal of a query tree, see [`Query::visit`]
+///
+/// All methods default to no-ops, implementors only override the ones they
+/// are interested in. Clauses are visited depth first, in the order they
+/// serialize.
+///
+/// To count the `term` clauses of a query:
+/// ```
+/// # use opensearch_dsl::*;
+/// struct TermCounter(usize);
+///
+/// impl QueryVisitor for TermCounter {
+///   fn visit_query(&mut self, query: &Query) {
+///     if let Query::Term(_) = query {
+///       self.0 += 1;
+///     }
+///   }
+/// }
+///
+/// let query = Query::from(
+///   Query::bool()
+///     .must(Query::term("user", "kimchy"))
+///     .filter(Query::term("status", "active")),
+/// );
+///
+/// let mut counter = TermCounter(0);
+/// query.visit(&mut counter);
+///
+/// assert_eq!(counter.0, 2);
+/// ```
+pub trait QueryVisitor {
+  /// Called for every query, before any of its clauses are visited
+  fn visit_query(&mut self, _query: &Query) {}
+
+  /// Called for every query, after all of its clauses were visited
+  fn leave_query(&mut self, _query: &Query) {}
+
+  /// Called for every span query, before any of its clauses are visited.
+  /// Span queries used directly as a [`Query`] are passed to
+  /// [`visit_query`](Self::visit_query) instead.
+  fn visit_span_query(&mut self, _query: &SpanQuery) {}
+
+  /// Called for every span query, after all of its clauses were visited
+  fn leave_span_query(&mut self, _query: &SpanQuery) {}
+
+  /// Called for the multi term query wrapped by a `span_multi` query
+  fn visit_multi_term_query(&mut self, _query: &MultiTermQuery) {}
+}
+
+/// Rewriting traversal of a query tree, see [`Query::fold`]
+///
+/// Clauses are folded bottom up, so by the time a compound query is passed to
+/// [`fold_query`](Self::fold_query) its clauses were already rewritten. The
+/// default implementations return the query unchanged.
+pub trait QueryFolder {
+  /// Rewrites a query whose clauses were already folded
+  fn fold_query(&mut self, query: Query) -> Query {
+    query
+  }
+
+  /// Rewrites a span query whose clauses were already folded
+  fn fold_span_query(&mut self, query: SpanQuery) -> SpanQuery {
+    query
+  }
+}
+
+impl Query {
+  /// Traverses the query and all of its clauses, including the ones nested
+  /// in compound, joining and span queries
+  pub fn visit<V>(&self, visitor: &mut V)
+  where
+    V: QueryVisitor + ?Sized, {
+    visitor.visit_query(self);
+
+    match self {
+      Self::Bool(q) => {
+        for clause in q
+          .must
+          .as_slice()
+          .iter()
+          .chain(q.filter.as_slice())
+          .chain(q.should.as_slice())
+          .chain(q.must_not.as_slice())
+        {
+          clause.visit(visitor);
+        }
+      }
+      Self::Boosting(q) => {
+        q.positive.visit(visitor);
+        q.negative.visit(visitor);
+      }
+      Self::ConstantScore(q) => q.filter.visit(visitor),
+      Self::DisMax(q) => {
+        for clause in q.queries.as_slice() {
+          clause.visit(visitor);
+        }
+      }
+      Self::FunctionScore(q) => {
+        if let Some(query) = &q.query {
+          query.visit(visitor);
+        }
+        for filter in q.functions.iter().filter_map(Function::filter_query) {
+          filter.visit(visitor);
+        }
+      }
+      Self::Pinned(q) => q.organic.visit(visitor),
+      Self::Nested(q) => q.query.visit(visitor),
+      Self::HasChild(q) => q.query.visit(visitor),
+      Self::HasParent(q) => q.query.visit(visitor),
+      Self::ScriptScore(q) => q.query.visit(visitor),
+      Self::Knn(q) => {
+        if let Some(filter) = &q.filter {
+          filter.visit(visitor);
+        }
+      }
+      Self::SpanContaining(q) => {
+        q.little.visit(visitor);
+        q.big.visit(visitor);
+      }
+      Self::SpanFieldMasking(q) => q.query.visit(visitor),
+      Self::SpanFirst(q) => q.r#match.visit(visitor),
+      Self::SpanMulti(q) => visitor.visit_multi_term_query(&q.r#match),
+      Self::SpanNear(q) => visit_span_queries(&q.clauses, visitor),
+      Self::SpanNot(q) => {
+        q.include.visit(visitor);
+        q.exclude.visit(visitor);
+      }
+      Self::SpanOr(q) => visit_span_queries(&q.clauses, visitor),
+      Self::SpanWithin(q) => {
+        q.little.visit(visitor);
+        q.big.visit(visitor);
+      }
+      _ => {}
+    }
+
+    visitor.leave_query(self);
+  }
+
+  /// Rebuilds the query by passing it and all of its clauses, including the
+  /// ones nested in compound, joining and span queries, through `folder`
+  ///
+  /// To add a tenant filter to every `bool` query:
+  /// ```
+  /// # use opensearch_dsl::*;
+  /// struct TenantFilter;
+  ///
+  /// impl QueryFolder for TenantFilter {
+  ///   fn fold_query(&mut self, query: Query) -> Query {
+  ///     match query {
+  ///       Query::Bool(q) => q.filter(Query::term("tenant", "acme")).into(),
+  ///       query => query,
+  ///     }
+  ///   }
+  /// }
+  ///
+  /// let query =
+  ///   Query::from(Query::bool().must(Query::term("user", "kimchy"))).fold(&mut TenantFilter);
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   Query::bool()
+  ///     .must(Query::term("user", "kimchy"))
+  ///     .filter(Query::term("tenant", "acme"))
+  /// );
+  /// ```
+  pub fn fold<F>(self, folder: &mut F) -> Self
+  where
+    F: QueryFolder + ?Sized, {
+    let query = match self {
+      Self::Bool(mut q) => {
+        q.must = q.must.map(|clause| clause.fold(folder));
+        q.filter = q.filter.map(|clause| clause.fold(folder));
+        q.should = q.should.map(|clause| clause.fold(folder));
+        q.must_not = q.must_not.map(|clause| clause.fold(folder));
+        Self::Bool(q)
+      }
+      Self::Boosting(mut q) => {
+        q.positive = Box::new(q.positive.fold(folder));
+        q.negative = Box::new(q.negative.fold(folder));
+        Self::Boosting(q)
+      }
+      Self::ConstantScore(mut q) => {
+        q.filter = Box::new(q.filter.fold(folder));
+        Self::ConstantScore(q)
+      }
+      Self::DisMax(mut q) => {
+        q.queries = q.queries.map(|clause| clause.fold(folder));
+        Self::DisMax(q)
+      }
+      Self::FunctionScore(mut q) => {
+        q.query = q.query.map(|query| Box::new(query.fold(folder)));
+        q.functions = q
+          .functions
+          .into_iter()
+          .map(|function| function.map_filter_query(|filter| filter.fold(folder)))
+          .collect();
+        Self::FunctionScore(q)
+      }
+      Self::Pinned(mut q) => {
+        q.organic = Box::new(q.organic.fold(folder));
+        Self::Pinned(q)
+      }
+      Self::Nested(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::Nested(q)
+      }
+      Self::HasChild(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::HasChild(q)
+      }
+      Self::HasParent(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::HasParent(q)
+      }
+      Self::ScriptScore(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::ScriptScore(q)
+      }
+      Self::Knn(mut q) => {
+        q.filter = q.filter.map(|filter| Box::new(filter.fold(folder)));
+        Self::Knn(q)
+      }
+      Self::SpanContaining(mut q) => {
+        q.little = Box::new(q.little.fold(folder));
+        q.big = Box::new(q.big.fold(folder));
+        Self::SpanContaining(q)
+      }
+      Self::SpanFieldMasking(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::SpanFieldMasking(q)
+      }
+      Self::SpanFirst(mut q) => {
+        q.r#match = Box::new(q.r#match.fold(folder));
+        Self::SpanFirst(q)
+      }
+      Self::SpanNear(mut q) => {
+        q.clauses = fold_span_queries(q.clauses, folder);
+        Self::SpanNear(q)
+      }
+      Self::SpanNot(mut q) => {
+        q.include = Box::new(q.include.fold(folder));
+        q.exclude = Box::new(q.exclude.fold(folder));
+        Self::SpanNot(q)
+      }
+      Self::SpanOr(mut q) => {
+        q.clauses = fold_span_queries(q.clauses, folder);
+        Self::SpanOr(q)
+      }
+      Self::SpanWithin(mut q) => {
+        q.little = Box::new(q.little.fold(folder));
+        q.big = Box::new(q.big.fold(folder));
+        Self::SpanWithin(q)
+      }
+      query => query,
+    };
+
+    folder.fold_query(query)
+  }
+}
+
+impl SpanQuery {
+  /// Traverses the span query and all of its clauses
+  pub fn visit<V>(&self, visitor: &mut V)
+  where
+    V: QueryVisitor + ?Sized, {
+    visitor.visit_span_query(self);
+
+    match self {
+      Self::SpanContaining(q) => {
+        q.little.visit(visitor);
+        q.big.visit(visitor);
+      }
+      Self::SpanFieldMasking(q) => q.query.visit(visitor),
+      Self::SpanFirst(q) => q.r#match.visit(visitor),
+      Self::SpanMulti(q) => visitor.visit_multi_term_query(&q.r#match),
+      Self::SpanNear(q) => visit_span_queries(&q.clauses, visitor),
+      Self::SpanNot(q) => {
+        q.include.visit(visitor);
+        q.exclude.visit(visitor);
+      }
+      Self::SpanOr(q) => visit_span_queries(&q.clauses, visitor),
+      Self::SpanTerm(_) => {}
+      Self::SpanWithin(q) => {
+        q.little.visit(visitor);
+        q.big.visit(visitor);
+      }
+    }
+
+    visitor.leave_span_query(self);
+  }
+
+  /// Rebuilds the span query by passing it and all of its clauses through
+  /// `folder`
+  pub fn fold<F>(self, folder: &mut F) -> Self
+  where
+    F: QueryFolder + ?Sized, {
+    let query = match self {
+      Self::SpanContaining(mut q) => {
+        q.little = Box::new(q.little.fold(folder));
+        q.big = Box::new(q.big.fold(folder));
+        Self::SpanContaining(q)
+      }
+      Self::SpanFieldMasking(mut q) => {
+        q.query = Box::new(q.query.fold(folder));
+        Self::SpanFieldMasking(q)
+      }
+      Self::SpanFirst(mut q) => {
+        q.r#match = Box::new(q.r#match.fold(folder));
+        Self::SpanFirst(q)
+      }
+      Self::SpanNear(mut q) => {
+        q.clauses = fold_span_queries(q.clauses, folder);
+        Self::SpanNear(q)
+      }
+      Self::SpanNot(mut q) => {
+        q.include = Box::new(q.include.fold(folder));
+        q.exclude = Box::new(q.exclude.fold(folder));
+        Self::SpanNot(q)
+      }
+      Self::SpanOr(mut q) => {
+        q.clauses = fold_span_queries(q.clauses, folder);
+        Self::SpanOr(q)
+      }
+      Self::SpanWithin(mut q) => {
+        q.little = Box::new(q.little.fold(folder));
+        q.big = Box::new(q.big.fold(folder));
+        Self::SpanWithin(q)
+      }
+      query @ (Self::SpanMulti(_) | Self::SpanTerm(_)) => query,
+    };
+
+    folder.fold_span_query(query)
+  }
+}
+
+fn visit_span_queries<V>(queries: &[SpanQuery], visitor: &mut V)
+where
+  V: QueryVisitor + ?Sized, {
+  for query in queries {
+    query.visit(visitor);
+  }
+}
+
+fn fold_span_queries<F>(queries: Vec<SpanQuery>, folder: &mut F) -> Vec<SpanQuery>
+where
+  F: QueryFolder + ?Sized, {
+  queries.into_iter().map(|query| query.fold(folder)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Default)]
+  struct TermCounter {
+    terms: usize,
+    span_terms: usize,
+  }
+
+  impl QueryVisitor for TermCounter {
+    fn visit_query(&mut self, query: &Query) {
+      if let Query::Term(_) = query {
+        self.terms += 1;
+      }
+    }
+
+    fn visit_span_query(&mut self, query: &SpanQuery) {
+      if let SpanQuery::SpanTerm(_) = query {
+        self.span_terms += 1;
+      }
+    }
+  }
+
+  struct TenantFilter;
+
+  impl QueryFolder for TenantFilter {
+    fn fold_query(&mut self, query: Query) -> Query {
+      match query {
+        Query::Bool(q) => q.filter(Query::term("tenant", "acme")).into(),
+        query => query,
+      }
+    }
+  }
+
+  #[test]
+  fn visits_term_leaves_in_all_compound_queries() {
+    let query = Query::from(
+      Query::bool()
+        .must(Query::term("a", 1))
+        .should(Query::dis_max().query([Query::from(Query::term("b", 2)), Query::from(Query::match_all())]))
+        .filter(Query::constant_score(Query::term("c", 3)))
+        .must_not(Query::boosting(Query::term("d", 4), Query::term("e", 5), 0.5))
+        .should(
+          Query::function_score()
+            .query(Query::term("f", 6))
+            .function(Weight::new(2.0)),
+        )
+        .should(Query::nested("comments", Query::term("comments.g", 7)))
+        .should(Query::has_child("answer", Query::term("h", 8)))
+        .should(Query::has_parent("question", Query::term("i", 9)))
+        .should(Query::span_near([
+          Query::span_term("body", "quick"),
+          Query::span_term("body", "fox"),
+        ])),
+    );
+
+    let mut counter = TermCounter::default();
+    query.visit(&mut counter);
+
+    assert_eq!(counter.terms, 9);
+    assert_eq!(counter.span_terms, 2);
+  }
+
+  #[test]
+  fn visits_pinned_organic_and_function_filters() {
+    let query = Query::from(
+      Query::bool()
+        .must(Query::pinned(PinnedQueryValues::ids([1]), Query::term("a", 1)))
+        .should(
+          Query::function_score()
+            .query(Query::term("b", 2))
+            .function(Weight::new(2.0).filter(Query::term("c", 3)))
+            .function(RandomScore::new().filter(Query::term("d", 4)))
+            .function(FieldValueFactor::new("likes")),
+        ),
+    );
+
+    let mut counter = TermCounter::default();
+    query.visit(&mut counter);
+
+    assert_eq!(counter.terms, 4);
+  }
+
+  #[test]
+  fn folds_pinned_organic_and_function_filters() {
+    let query = Query::from(
+      Query::function_score()
+        .query(Query::pinned(
+          PinnedQueryValues::ids([1]),
+          Query::bool().must(Query::term("a", 1)),
+        ))
+        .function(Weight::new(2.0).filter(Query::bool().must(Query::term("b", 2)))),
+    )
+    .fold(&mut TenantFilter);
+
+    assert_eq!(
+      query,
+      Query::function_score()
+        .query(Query::pinned(
+          PinnedQueryValues::ids([1]),
+          Query::bool()
+            .must(Query::term("a", 1))
+            .filter(Query::term("tenant", "acme")),
+        ))
+        .function(
+          Weight::new(2.0).filter(
+            Query::bool()
+              .must(Query::term("b", 2))
+              .filter(Query::term("tenant", "acme"))
+          )
+        )
+    );
+  }
+
+  #[test]
+  fn folds_nested_bool_queries() {
+    let query = Query::from(Query::bool().must(Query::term("user", "kimchy")).should(Query::nested(
+      "comments",
+      Query::bool().must(Query::term("comments.author", "kimchy")),
+    )))
+    .fold(&mut TenantFilter);
+
+    assert_eq!(
+      query,
+      Query::bool()
+        .must(Query::term("user", "kimchy"))
+        .should(Query::nested(
+          "comments",
+          Query::bool()
+            .must(Query::term("comments.author", "kimchy"))
+            .filter(Query::term("tenant", "acme"))
+        ))
+        .filter(Query::term("tenant", "acme"))
+    );
+  }
+}
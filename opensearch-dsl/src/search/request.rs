@@ -1,6 +1,6 @@
 //! Allows you to execute a search query and get back search hits that match the
 //! query.
-use crate::{search::*, util::*, Map, Set};
+use crate::{search::*, util::*, Map};
 
 /// Returns search hits that match the query defined in the request.
 ///
@@ -41,7 +41,10 @@ pub struct Search {
   track_total_hits: Option<TrackTotalHits>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  highlight: Option<Highlight>,
+  terminate_after: Option<u64>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  highlight: Highlight,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   rescore: RescoreCollection,
@@ -53,7 +56,10 @@ pub struct Search {
   stored_fields: StoredFields,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
-  docvalue_fields: Set<String>,
+  docvalue_fields: Vec<DocValueField>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  fields: Vec<FieldAndFormat>,
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   script_fields: Map<String, ScriptField>,
@@ -82,6 +88,12 @@ pub struct Search {
 
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   track_scores: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  profile: Option<bool>,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  explain: Option<bool>,
 }
 
 impl Search {
@@ -109,6 +121,15 @@ impl Search {
     self
   }
 
+  /// Adds a single script field computed for each hit, returned under
+  /// `fields.<name>`
+  pub fn script_field<S>(mut self, name: S, script: ScriptField) -> Self
+  where
+    S: ToString, {
+    let _ = self.script_fields.insert(name.to_string(), script);
+    self
+  }
+
   /// Allows to configure different boost level per index when searching
   /// across more than one indices. This is very handy when hits coming from
   /// one index matter more than hits coming from another index (think social
@@ -123,6 +144,18 @@ impl Search {
     self
   }
 
+  /// Alias of [`indices_boost`](Self::indices_boost), adding the boost of a
+  /// single index or index pattern.
+  ///
+  /// Entries are serialized in the order they were added, which matters when
+  /// an index matches more than one pattern.
+  pub fn index_boost<T, U>(self, index: T, boost: U) -> Self
+  where
+    T: ToString,
+    U: num_traits::AsPrimitive<f32>, {
+    self.indices_boost(index, boost)
+  }
+
   /// Exclude documents which have a `_score` less than the minimum specified
   /// in `min_score`
   ///
@@ -144,6 +177,8 @@ impl Search {
   }
 
   /// Specific `tag` of the request for logging and statistical purposes.
+  ///
+  /// Each call adds another group, the `stats` array is omitted while empty.
   pub fn stats<S>(mut self, stats: S) -> Self
   where
     S: ToString, {
@@ -203,6 +238,13 @@ impl Search {
     self
   }
 
+  /// The maximum number of documents to collect for each shard. If a query
+  /// reaches this limit, OpenSearch terminates the query early.
+  pub fn terminate_after(mut self, terminate_after: u64) -> Self {
+    self.terminate_after = Some(terminate_after);
+    self
+  }
+
   /// If true, calculate and return document scores, even if the scores are not
   /// used for sorting.
   pub fn track_scores(mut self, enabled: bool) -> Self {
@@ -210,11 +252,26 @@ impl Search {
     self
   }
 
+  /// If true, returns detailed timing information about the execution of the
+  /// individual components of the search request in the `profile` section of
+  /// the response
+  pub fn profile(mut self, enabled: bool) -> Self {
+    self.profile = Some(enabled);
+    self
+  }
+
+  /// If true, returns detailed information about score computation as part
+  /// of each hit's `_explanation`
+  pub fn explain(mut self, enabled: bool) -> Self {
+    self.explain = Some(enabled);
+    self
+  }
+
   /// Highlight
   pub fn highlight<H>(mut self, highlight: H) -> Self
   where
     H: Into<Highlight>, {
-    self.highlight = Some(highlight.into());
+    self.highlight = highlight.into();
     self
   }
 
@@ -248,10 +305,26 @@ impl Search {
   pub fn docvalue_fields<T>(mut self, docvalue_fields: T) -> Self
   where
     T: IntoIterator,
-    T::Item: ToString, {
+    T::Item: Into<DocValueField>, {
+    self.docvalue_fields.extend(docvalue_fields.into_iter().map(Into::into));
     self
-      .docvalue_fields
-      .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+  }
+
+  /// Adds a single docvalue field, optionally with a custom format
+  pub fn docvalue_field<T>(mut self, docvalue_field: T) -> Self
+  where
+    T: Into<DocValueField>, {
+    self.docvalue_fields.push(docvalue_field.into());
+    self
+  }
+
+  /// A collection of fields to retrieve formatted values for, including
+  /// runtime fields
+  pub fn fields<T>(mut self, fields: T) -> Self
+  where
+    T: IntoIterator,
+    T::Item: Into<FieldAndFormat>, {
+    self.fields.extend(fields.into_iter().map(Into::into));
     self
   }
 
@@ -262,6 +335,11 @@ impl Search {
   }
 
   /// Search after a set of sort values.
+  ///
+  /// Pass the `sort` values of the last hit of the previous page to retrieve
+  /// the next page. Omitted from the request when empty.
+  ///
+  /// <https://opensearch.org/docs/latest/search-plugins/searching-data/paginate/#the-search_after-parameter>
   pub fn search_after<T>(mut self, sort_values: T) -> Self
   where
     T: Into<Terms>, {
@@ -301,7 +379,7 @@ impl Search {
   /// Extra fields for something not yet supported.
   ///
   /// ```
-  /// # use elasticsearch_dsl::Search;
+  /// # use opensearch_dsl::Search;
   /// # use serde_json::json;
   /// # let search =
   /// Search::new()
@@ -351,4 +429,107 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_search_after() {
+    let last_hit_sort = vec![json!(1463538857), json!("654323")];
+
+    assert_serialize(
+      Search::new()
+        .sort(FieldSort::descending("date"))
+        .search_after(last_hit_sort),
+      json!({
+          "sort": [{ "date": { "order": "desc" } }],
+          "search_after": [1463538857, "654323"],
+      }),
+    );
+
+    assert_serialize(Search::new().search_after(Vec::<serde_json::Value>::new()), json!({}));
+  }
+
+  #[test]
+  fn serializes_track_total_hits_and_terminate_after() {
+    assert_serialize(
+      Search::new().track_total_hits(true).terminate_after(1000),
+      json!({
+          "track_total_hits": true,
+          "terminate_after": 1000,
+      }),
+    );
+
+    assert_serialize(
+      Search::new().track_total_hits(10_000),
+      json!({
+          "track_total_hits": 10000,
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_profile_and_explain_flags() {
+    assert_serialize(
+      Search::new().profile(true).explain(true),
+      json!({
+          "profile": true,
+          "explain": true,
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_stats_groups_only_when_set() {
+    assert_serialize(Search::new().size(0), json!({ "size": 0 }));
+
+    assert_serialize(
+      Search::new().stats("dashboard").stats("nightly-report"),
+      json!({
+          "stats": ["dashboard", "nightly-report"],
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_script_field() {
+    assert_serialize(
+      Search::new().script_field(
+        "price_with_tax",
+        ScriptField::new(Script::source("doc['price'].value * 1.2").lang("painless")),
+      ),
+      json!({
+          "script_fields": {
+              "price_with_tax": {
+                  "script": {
+                      "lang": "painless",
+                      "source": "doc['price'].value * 1.2"
+                  }
+              }
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_indices_boost_in_insertion_order() {
+    assert_serialize(
+      Search::new()
+        .index_boost("logs-2024", 2.0)
+        .index_boost("logs-*", 1.5)
+        .index_boost("archive", 0.5),
+      json!({
+          "indices_boost": [
+              { "logs-2024": 2.0 },
+              { "logs-*": 1.5 },
+              { "archive": 0.5 }
+          ]
+      }),
+    );
+  }
+
+  #[test]
+  fn index_boost_is_an_alias_of_indices_boost() {
+    assert_eq!(
+      Search::new().index_boost("logs-*", 1.5),
+      Search::new().indices_boost("logs-*", 1.5)
+    );
+  }
 }
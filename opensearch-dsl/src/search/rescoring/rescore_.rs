@@ -99,6 +99,7 @@ impl Rescore {
   }
 
   /// The way the scores are combined can be controlled with the
+  /// `score_mode`. Defaults to `total`.
   pub fn score_mode(mut self, score_mode: ScoreMode) -> Self {
     self.query.score_mode = Some(score_mode);
     self
@@ -123,7 +124,10 @@ impl IntoIterator for Rescore {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::util::assert_serialize_rescore;
+  use crate::{
+    util::{assert_serialize, assert_serialize_rescore},
+    Search,
+  };
 
   #[test]
   fn should_skip() {
@@ -171,4 +175,57 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_match_phrase_rescorer() {
+    assert_serialize(
+      Search::new()
+        .query(Query::r#match("message", "the quick brown"))
+        .rescore(
+          Rescore::new(Query::match_phrase("message", "the quick brown").slop(2))
+            .window_size(50)
+            .query_weight(0.7)
+            .rescore_query_weight(1.2),
+        ),
+      json!({
+          "query": { "match": { "message": { "query": "the quick brown" } } },
+          "rescore": [
+              {
+                  "window_size": 50,
+                  "query": {
+                      "rescore_query": {
+                          "match_phrase": {
+                              "message": { "query": "the quick brown", "slop": 2 }
+                          }
+                      },
+                      "query_weight": 0.7,
+                      "rescore_query_weight": 1.2
+                  }
+              }
+          ]
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_multiple_rescorers_in_order() {
+    assert_serialize(
+      Search::new().rescore([
+        Rescore::new(Query::term("title", "first")).window_size(100),
+        Rescore::new(Query::term("title", "second")).window_size(10),
+      ]),
+      json!({
+          "rescore": [
+              {
+                  "window_size": 100,
+                  "query": { "rescore_query": { "term": { "title": { "value": "first" } } } }
+              },
+              {
+                  "window_size": 10,
+                  "query": { "rescore_query": { "term": { "title": { "value": "second" } } } }
+              }
+          ]
+      }),
+    );
+  }
 }
@@ -1,4 +1,4 @@
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::Value;
 
 use super::{Explanation, NestedIdentity, Source};
@@ -40,8 +40,13 @@ pub struct Hit {
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   pub inner_hits: Map<String, InnerHitsResult>,
 
-  /// Matched queries
-  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  /// Names of the matched named queries. Also accepts the `{name: score}`
+  /// form returned when `include_named_queries_score` is set
+  #[serde(
+    default,
+    skip_serializing_if = "ShouldSkip::should_skip",
+    deserialize_with = "deserialize_matched_queries"
+  )]
   pub matched_queries: Vec<String>,
 
   /// Values document was sorted by
@@ -53,6 +58,22 @@ pub struct Hit {
   pub fields: Map<String, Value>,
 }
 
+fn deserialize_matched_queries<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+  D: serde::Deserializer<'de>, {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum MatchedQueries {
+    Names(Vec<String>),
+    Scores(Map<String, f64>),
+  }
+
+  Ok(match MatchedQueries::deserialize(deserializer)? {
+    MatchedQueries::Names(names) => names,
+    MatchedQueries::Scores(scores) => scores.into_keys().collect(),
+  })
+}
+
 impl Hit {
   /// Parses document source into a concrete type
   pub fn source<T>(&self) -> Result<T, serde_json::Error>
@@ -61,3 +82,32 @@ impl Hit {
     self.source.parse()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deserializes_matched_queries_list() {
+    let hit: Hit = serde_json::from_value(json!({
+        "_index": "test",
+        "_id": "1",
+        "matched_queries": ["name_first", "name_last"]
+    }))
+    .unwrap();
+
+    assert_eq!(hit.matched_queries, ["name_first", "name_last"]);
+  }
+
+  #[test]
+  fn deserializes_matched_queries_scores() {
+    let hit: Hit = serde_json::from_value(json!({
+        "_index": "test",
+        "_id": "1",
+        "matched_queries": { "name_first": 1.2, "name_last": 0.5 }
+    }))
+    .unwrap();
+
+    assert_eq!(hit.matched_queries, ["name_first", "name_last"]);
+  }
+}
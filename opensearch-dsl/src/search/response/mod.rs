@@ -5,6 +5,7 @@ mod hit;
 mod hits_metadata;
 mod inner_hits_result;
 mod nested_identity;
+mod profile;
 mod search_response;
 mod shard_failure;
 mod shard_statistics;
@@ -16,6 +17,6 @@ mod total_hits_relation;
 
 pub use self::{
   cluster_statistics::*, error_cause::*, explanation::*, hit::*, hits_metadata::*, inner_hits_result::*,
-  nested_identity::*, search_response::*, shard_failure::*, shard_statistics::*, source::*, suggest::*,
+  nested_identity::*, profile::*, search_response::*, shard_failure::*, shard_statistics::*, source::*, suggest::*,
   suggest_option::*, total_hits::*, total_hits_relation::*,
 };
@@ -0,0 +1,152 @@
+use crate::Map;
+
+/// Timing information returned when a search request is sent with
+/// `profile: true`
+///
+/// <https://opensearch.org/docs/latest/api-reference/profile/>
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Profile {
+  /// Profiled shards
+  #[serde(default)]
+  pub shards: Vec<ShardProfile>,
+}
+
+/// Timing information of a single shard
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ShardProfile {
+  /// Shard identifier in the `[node][index][shard]` form
+  pub id: String,
+
+  /// Profiled searches executed on the shard
+  #[serde(default)]
+  pub searches: Vec<SearchProfile>,
+}
+
+/// Timing information of a single search executed on a shard
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SearchProfile {
+  /// Profiled query tree
+  #[serde(default)]
+  pub query: Vec<QueryProfile>,
+
+  /// Time spent rewriting the query, in nanoseconds
+  #[serde(default)]
+  pub rewrite_time: u64,
+
+  /// Profiled collector tree
+  #[serde(default)]
+  pub collector: Vec<CollectorProfile>,
+}
+
+/// Timing information of a Lucene query
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct QueryProfile {
+  /// Lucene class name of the query
+  #[serde(rename = "type")]
+  pub r#type: String,
+
+  /// Lucene explanation of the query
+  pub description: String,
+
+  /// Total time spent executing the query and its children, in nanoseconds
+  pub time_in_nanos: u64,
+
+  /// Time spent in each of the low level Lucene methods, in nanoseconds, and
+  /// the number of times they were invoked
+  #[serde(default)]
+  pub breakdown: Map<String, u64>,
+
+  /// Profiled sub-queries
+  #[serde(default)]
+  pub children: Vec<QueryProfile>,
+}
+
+/// Timing information of a Lucene collector
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct CollectorProfile {
+  /// Lucene class name of the collector
+  pub name: String,
+
+  /// Description of the collector's purpose
+  pub reason: String,
+
+  /// Total time spent collecting, in nanoseconds
+  pub time_in_nanos: u64,
+
+  /// Wrapped collectors
+  #[serde(default)]
+  pub children: Vec<CollectorProfile>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deserializes_query_and_collector_trees() {
+    let json = json!({
+      "shards": [
+        {
+          "id": "[d2WxnN2QTsOh8fSRauG8ZQ][movies][0]",
+          "searches": [
+            {
+              "query": [
+                {
+                  "type": "BooleanQuery",
+                  "description": "+title:godfather #genre:crime",
+                  "time_in_nanos": 53052,
+                  "breakdown": {
+                    "create_weight": 16939,
+                    "create_weight_count": 1,
+                    "score": 4019,
+                    "score_count": 3
+                  },
+                  "children": [
+                    {
+                      "type": "TermQuery",
+                      "description": "title:godfather",
+                      "time_in_nanos": 14536,
+                      "breakdown": { "score": 3087, "score_count": 3 }
+                    }
+                  ]
+                }
+              ],
+              "rewrite_time": 8922,
+              "collector": [
+                {
+                  "name": "SimpleTopScoreDocCollector",
+                  "reason": "search_top_hits",
+                  "time_in_nanos": 25678,
+                  "children": [
+                    {
+                      "name": "MultiCollector",
+                      "reason": "search_multi",
+                      "time_in_nanos": 2100
+                    }
+                  ]
+                }
+              ]
+            }
+          ],
+          "aggregations": []
+        }
+      ]
+    });
+
+    let profile: Profile = serde_json::from_value(json).unwrap();
+    let search = &profile.shards[0].searches[0];
+
+    assert_eq!(profile.shards[0].id, "[d2WxnN2QTsOh8fSRauG8ZQ][movies][0]");
+    assert_eq!(search.rewrite_time, 8922);
+
+    assert_eq!(search.query[0].r#type, "BooleanQuery");
+    assert_eq!(search.query[0].time_in_nanos, 53052);
+    assert_eq!(search.query[0].breakdown.get("score_count"), Some(&3));
+    assert_eq!(search.query[0].children[0].description, "title:godfather");
+    assert!(search.query[0].children[0].children.is_empty());
+
+    assert_eq!(search.collector[0].reason, "search_top_hits");
+    assert_eq!(search.collector[0].children[0].name, "MultiCollector");
+    assert_eq!(search.collector[0].children[0].time_in_nanos, 2100);
+  }
+}
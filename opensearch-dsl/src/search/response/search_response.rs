@@ -1,7 +1,7 @@
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use super::{ClusterStatistics, HitsMetadata, ShardStatistics, Suggest};
+use super::{ClusterStatistics, HitsMetadata, Profile, ShardStatistics, Suggest};
 use crate::{util::ShouldSkip, Map};
 
 /// Search response
@@ -57,6 +57,10 @@ pub struct SearchResponse {
   /// Suggest response
   #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
   pub suggest: Map<String, Vec<Suggest>>,
+
+  /// Profiling results, present when the request enabled `profile`
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  pub profile: Option<Profile>,
 }
 
 impl SearchResponse {
@@ -261,6 +265,7 @@ mod tests {
           }],
         ),
       ]),
+      profile: None,
     };
 
     assert_eq!(actual, expected);
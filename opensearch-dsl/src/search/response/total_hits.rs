@@ -1,7 +1,9 @@
+use serde::{Deserialize, Deserializer};
+
 use super::TotalHitsRelation;
 
 /// Total number of matched documents
-#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Serialize, PartialEq, Eq)]
 pub struct TotalHits {
   /// Number of total documents
   pub value: u64,
@@ -19,3 +21,53 @@ impl TotalHits {
     }
   }
 }
+
+impl<'de> Deserialize<'de> for TotalHits {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>, {
+    // `rest_total_hits_as_int` and older clusters report a bare integer
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+      Count(u64),
+      Object { value: u64, relation: TotalHitsRelation },
+    }
+
+    Ok(match Repr::deserialize(deserializer)? {
+      Repr::Count(value) => Self::new(Some(value)),
+      Repr::Object { value, relation } => Self { value, relation },
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deserializes_object() {
+    let total: TotalHits = serde_json::from_value(json!({ "value": 10000, "relation": "gte" })).unwrap();
+
+    assert_eq!(
+      total,
+      TotalHits {
+        value: 10000,
+        relation: TotalHitsRelation::GreaterThanOrEqualTo,
+      }
+    );
+  }
+
+  #[test]
+  fn deserializes_integer() {
+    let total: TotalHits = serde_json::from_value(json!(42)).unwrap();
+
+    assert_eq!(
+      total,
+      TotalHits {
+        value: 42,
+        relation: TotalHitsRelation::Equal,
+      }
+    );
+  }
+}
@@ -18,6 +18,8 @@ use serde::{
   Deserialize, Deserializer,
 };
 
+use crate::Script;
+
 /// A runtime data type that is used in a search request.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub enum RuntimeDataType {
@@ -140,29 +142,25 @@ impl<'de> Deserialize<'de> for RuntimeDataType {
 }
 
 /// A runtime field that is used in a search request.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RuntimeMapping {
   #[serde(flatten)]
   r#type: RuntimeDataType,
-  script: RuntimeScript,
-}
-
-#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
-struct RuntimeScript {
-  source: String,
+  script: Script,
 }
 
 impl RuntimeMapping {
-  /// Creates a new instance of [RuntimeMapping]
+  /// Creates a new instance of [RuntimeMapping] from an inline script source
   pub fn new<T>(r#type: RuntimeDataType, source: T) -> Self
   where
     T: ToString, {
-    RuntimeMapping {
-      r#type,
-      script: RuntimeScript {
-        source: source.to_string(),
-      },
-    }
+    Self::with_script(r#type, Script::source(source))
+  }
+
+  /// Creates a new instance of [RuntimeMapping] from a [Script], allowing the
+  /// script language and parameters to be specified
+  pub fn with_script(r#type: RuntimeDataType, script: Script) -> Self {
+    RuntimeMapping { r#type, script }
   }
 
   /// Creates a new instance of [RuntimeDataType::Boolean] [RuntimeMapping]
@@ -235,7 +233,7 @@ impl RuntimeMapping {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::util::*;
+  use crate::{util::*, Aggregation, Query, Search};
 
   #[test]
   fn serialization() {
@@ -312,4 +310,45 @@ mod tests {
       }),
     );
   }
+
+  #[test]
+  fn serializes_in_search_request() {
+    assert_serialize(
+      Search::new()
+        .runtime_mapping(
+          "day_of_week",
+          RuntimeMapping::with_script(
+            RuntimeDataType::Keyword,
+            Script::source("emit(doc['@timestamp'].value.dayOfWeekEnum.toString())").lang("painless"),
+          ),
+        )
+        .query(Query::term("day_of_week", "MONDAY"))
+        .aggregate("by_day", Aggregation::terms("day_of_week")),
+      json!({
+          "runtime_mappings": {
+              "day_of_week": {
+                  "type": "keyword",
+                  "script": {
+                      "source": "emit(doc['@timestamp'].value.dayOfWeekEnum.toString())",
+                      "lang": "painless"
+                  }
+              }
+          },
+          "query": {
+              "term": {
+                  "day_of_week": {
+                      "value": "MONDAY"
+                  }
+              }
+          },
+          "aggs": {
+              "by_day": {
+                  "terms": {
+                      "field": "day_of_week"
+                  }
+              }
+          }
+      }),
+    );
+  }
 }
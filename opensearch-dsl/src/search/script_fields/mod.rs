@@ -4,33 +4,53 @@
 //!
 //! <https://www.elastic.co/guide/en/opensearch/reference/current/search-fields.html#script-fields>
 
-use crate::Script;
+use crate::{util::*, Script};
 
 /// A script to calculate field value from the `_source` fields
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ScriptField {
   script: Script,
+
+  #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+  ignore_failure: Option<bool>,
+}
+
+impl ScriptField {
+  /// Creates an instance of [`ScriptField`]
+  ///
+  /// - `script` - Script used to compute the field value for each hit
+  pub fn new<T>(script: T) -> Self
+  where
+    T: Into<Script>, {
+    Self {
+      script: script.into(),
+      ignore_failure: None,
+    }
+  }
+
+  /// When `true`, a script evaluation error for a hit is ignored instead of
+  /// failing the whole request.
+  pub fn ignore_failure(mut self, ignore_failure: bool) -> Self {
+    self.ignore_failure = Some(ignore_failure);
+    self
+  }
 }
 
 impl From<Script> for ScriptField {
   fn from(value: Script) -> Self {
-    Self { script: value }
+    Self::new(value)
   }
 }
 
 impl From<&str> for ScriptField {
   fn from(value: &str) -> Self {
-    Self {
-      script: Script::source(value),
-    }
+    Self::new(Script::source(value))
   }
 }
 
 impl From<String> for ScriptField {
   fn from(value: String) -> Self {
-    Self {
-      script: Script::source(value),
-    }
+    Self::new(Script::source(value))
   }
 }
 
@@ -78,4 +98,25 @@ mod tests {
 
     assert_serialize(subject, expectation);
   }
+
+  #[test]
+  fn serializes_ignore_failure() {
+    assert_serialize(
+      Search::new().script_fields(
+        "discounted_price",
+        ScriptField::new(Script::source("doc['price'].value * 0.9").lang("painless")).ignore_failure(true),
+      ),
+      json!({
+          "script_fields": {
+              "discounted_price": {
+                  "script": {
+                      "lang": "painless",
+                      "source": "doc['price'].value * 0.9"
+                  },
+                  "ignore_failure": true
+              }
+          }
+      }),
+    );
+  }
 }
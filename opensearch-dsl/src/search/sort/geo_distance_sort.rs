@@ -260,7 +260,7 @@ mod tests {
       GeoDistanceSort::new("test", GeoLocation::new(1.2, 3.3)),
       json!({
           "_geo_distance": {
-              "test": [ [3.3, 1.2] ]
+              "test": [ { "lat": 1.2, "lon": 3.3 } ]
           }
       }),
     );
@@ -269,7 +269,7 @@ mod tests {
       GeoDistanceSort::ascending("test", GeoLocation::new(1.2, 3.3)),
       json!({
           "_geo_distance": {
-              "test": [ [3.3, 1.2] ],
+              "test": [ { "lat": 1.2, "lon": 3.3 } ],
               "order": "asc",
           }
       }),
@@ -284,7 +284,7 @@ mod tests {
         .ignore_unmapped(true),
       json!({
           "_geo_distance": {
-              "test": [ [3.3, 1.2] ],
+              "test": [ { "lat": 1.2, "lon": 3.3 } ],
               "unit": "in",
               "order": "asc",
               "mode": "max",
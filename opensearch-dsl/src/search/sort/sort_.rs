@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use super::{FieldSort, GeoDistanceSort, ScriptSort, SortSpecialField};
+use crate::GeoLocation;
 
 /// Sorting criterion
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
@@ -22,6 +23,35 @@ pub enum Sort {
   ScriptSort(ScriptSort),
 }
 
+impl Sort {
+  /// Creates a [`FieldSort`] for the given field, allowing finer control over
+  /// order, mode, missing values and unmapped types.
+  ///
+  /// ```
+  /// # use opensearch_dsl::*;
+  /// # let sort: Sort =
+  /// Sort::field("price")
+  ///   .order(SortOrder::Desc)
+  ///   .mode(SortMode::Avg)
+  ///   .missing(SortMissing::Last)
+  ///   .into();
+  /// ```
+  pub fn field<T>(field: T) -> FieldSort
+  where
+    T: ToString, {
+    FieldSort::new(field)
+  }
+
+  /// Creates a [`GeoDistanceSort`] that orders hits by their distance from
+  /// the given location.
+  pub fn geo_distance<T, U>(field: T, location: U) -> GeoDistanceSort
+  where
+    T: ToString,
+    U: Into<GeoLocation>, {
+    GeoDistanceSort::new(field, [location.into()])
+  }
+}
+
 impl std::fmt::Debug for Sort {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
@@ -84,3 +114,58 @@ impl IntoIterator for Sort {
     Some(self).into_iter()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{util::assert_serialize, DistanceUnit, GeoDistanceType, SortMissing, SortMode, SortOrder};
+
+  #[test]
+  fn serializes_field_name_as_string() {
+    assert_serialize(Sort::from("price"), json!("price"));
+    assert_serialize(Sort::from(SortSpecialField::Score), json!("_score"));
+  }
+
+  #[test]
+  fn serializes_field_sort_as_object() {
+    assert_serialize(
+      Sort::from(
+        Sort::field("price")
+          .order(SortOrder::Desc)
+          .mode(SortMode::Avg)
+          .missing(SortMissing::Last)
+          .unmapped_type("long"),
+      ),
+      json!({
+          "price": {
+              "order": "desc",
+              "mode": "avg",
+              "missing": "_last",
+              "unmapped_type": "long"
+          }
+      }),
+    );
+  }
+
+  #[test]
+  fn serializes_geo_distance_sort() {
+    assert_serialize(
+      Sort::from(
+        Sort::geo_distance("pin.location", GeoLocation::new(40.0, -70.0))
+          .order(SortOrder::Asc)
+          .unit(DistanceUnit::Kilometers)
+          .distance_type(GeoDistanceType::Plane)
+          .mode(SortMode::Min),
+      ),
+      json!({
+          "_geo_distance": {
+              "pin.location": [{ "lat": 40.0, "lon": -70.0 }],
+              "order": "asc",
+              "unit": "km",
+              "distance_type": "plane",
+              "mode": "min"
+          }
+      }),
+    );
+  }
+}
@@ -1,4 +1,7 @@
-use serde::ser::{Serialize, Serializer};
+use serde::{
+  de::{Deserialize, Deserializer, IntoDeserializer},
+  ser::{Serialize, Serializer},
+};
 
 pub(crate) fn join_with_pipe<S, T>(value: &[T], serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -12,6 +15,19 @@ where
     .serialize(serializer)
 }
 
+pub(crate) fn split_with_pipe<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de>, {
+  let value = String::deserialize(deserializer)?;
+
+  value
+    .split('|')
+    .filter(|part| !part.is_empty())
+    .map(|part| T::deserialize(part.into_deserializer()))
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -27,4 +43,17 @@ mod tests {
 
     assert_serialize(JoinWithPipe { value: &[1, 2, 3] }, json!({ "value": "1|2|3" }))
   }
+
+  #[test]
+  fn tests_deserialization() {
+    #[derive(Deserialize)]
+    struct SplitWithPipe {
+      #[serde(deserialize_with = "split_with_pipe")]
+      value: Vec<crate::RegexpFlag>,
+    }
+
+    let result: SplitWithPipe = serde_json::from_value(json!({ "value": "COMPLEMENT|EMPTY" })).unwrap();
+
+    assert_eq!(result.value, [crate::RegexpFlag::Complement, crate::RegexpFlag::Empty]);
+  }
 }